@@ -0,0 +1,416 @@
+//! Base58Check encoding of XRPL addresses, seeds and public keys
+//!
+//! https://xrpl.org/docs/references/protocol/data-types/base58-encodings
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    public_key::{KeyType, PublicKey},
+    types::AccountIdType,
+    Error, Vec,
+};
+
+/// The XRPL base58 alphabet
+const ALPHABET: &[u8; 58] = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+/// Version prefix of a classic address i.e. 'r...'
+const ACCOUNT_ID_VERSION: [u8; 1] = [0x00];
+/// Version prefix of a main network X-address i.e. 'X...'
+const X_ADDRESS_MAIN_NETWORK_VERSION: [u8; 2] = [0x05, 0x44];
+/// Version prefix of a test network X-address i.e. 'T...'
+const X_ADDRESS_TEST_NETWORK_VERSION: [u8; 2] = [0x04, 0x93];
+/// Version prefix of a (secp256k1) family seed i.e. 's...'
+const SEED_VERSION: [u8; 1] = [0x21];
+/// Version prefix of an ed25519 family seed i.e. 'sEd...'
+const ED25519_SEED_VERSION: [u8; 3] = [0x01, 0xe1, 0x4b];
+/// Version prefix of a node (validator) public key i.e. 'n...'
+const NODE_PUBLIC_KEY_VERSION: [u8; 1] = [0x1c];
+/// Version prefix of an account public key i.e. 'a...'
+const ACCOUNT_PUBLIC_KEY_VERSION: [u8; 1] = [0x23];
+/// Length of the Base58Check checksum
+const CHECKSUM_LENGTH: usize = 4;
+
+/// Encode `account_id` as a classic address e.g. "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+pub fn encode_classic_address(account_id: &AccountIdType) -> String {
+    encode_base58_check(&ACCOUNT_ID_VERSION, &account_id.0)
+}
+
+/// Decode the account ID of a classic address e.g. "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"
+pub fn decode_classic_address(address: &str) -> Result<AccountIdType, Error> {
+    let payload = decode_base58_check(address, &ACCOUNT_ID_VERSION, 20)?;
+    Ok(AccountIdType(payload.try_into().expect("it is 20 bytes")))
+}
+
+/// Encode a 16 byte family `seed` e.g. "sn259rEFXrQrWyx3Q7XneWcwV6dfL" or "sEdTM1uX8pu2do5XvTnutH6HsouMaM2" (ed25519)
+pub fn encode_seed(seed: &[u8; 16], key_type: KeyType) -> String {
+    match key_type {
+        KeyType::Secp256k1 => encode_base58_check(&SEED_VERSION, seed),
+        KeyType::Ed25519 => encode_base58_check(&ED25519_SEED_VERSION, seed),
+    }
+}
+
+/// Decode a family seed and the type of key it derives e.g. "sn259rEFXrQrWyx3Q7XneWcwV6dfL"
+pub fn decode_seed(seed: &str) -> Result<([u8; 16], KeyType), Error> {
+    // the key types are told apart by the length of their versions
+    let data = decode_base58(seed)?;
+    let (version, key_type) = if data.len() == ED25519_SEED_VERSION.len() + 16 + CHECKSUM_LENGTH {
+        (ED25519_SEED_VERSION.as_slice(), KeyType::Ed25519)
+    } else {
+        (SEED_VERSION.as_slice(), KeyType::Secp256k1)
+    };
+    let payload = check_base58(&data, version, 16)?;
+    Ok((payload.try_into().expect("it is 16 bytes"), key_type))
+}
+
+/// Encode a node (validator) public key e.g. "n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH"
+pub fn encode_node_public_key(public_key: &PublicKey) -> String {
+    encode_base58_check(&NODE_PUBLIC_KEY_VERSION, &public_key.to_bytes())
+}
+
+/// Decode a node (validator) public key e.g. "n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH"
+pub fn decode_node_public_key(public_key: &str) -> Result<PublicKey, Error> {
+    PublicKey::try_from(decode_base58_check(public_key, &NODE_PUBLIC_KEY_VERSION, 33)?.as_slice())
+}
+
+/// Encode an account public key e.g. "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3"
+pub fn encode_account_public_key(public_key: &PublicKey) -> String {
+    encode_base58_check(&ACCOUNT_PUBLIC_KEY_VERSION, &public_key.to_bytes())
+}
+
+/// Decode an account public key e.g. "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3"
+pub fn decode_account_public_key(public_key: &str) -> Result<PublicKey, Error> {
+    PublicKey::try_from(
+        decode_base58_check(public_key, &ACCOUNT_PUBLIC_KEY_VERSION, 33)?.as_slice(),
+    )
+}
+
+/// An X-address i.e. an account ID with optional destination tag for a main or test network
+///
+/// https://xrpl.org/docs/concepts/accounts/addresses#x-addresses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XAddress {
+    pub account_id: AccountIdType,
+    pub destination_tag: Option<u32>,
+    /// Whether the address is for a test network (i.e. 'T...') or the main network (i.e. 'X...')
+    pub is_test_network: bool,
+}
+
+impl XAddress {
+    /// Encode as an X-address e.g. "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC"
+    pub fn encode(&self) -> String {
+        let version = if self.is_test_network {
+            X_ADDRESS_TEST_NETWORK_VERSION
+        } else {
+            X_ADDRESS_MAIN_NETWORK_VERSION
+        };
+        let mut payload = [0_u8; 29];
+        payload[..20].copy_from_slice(&self.account_id.0);
+        if let Some(destination_tag) = self.destination_tag {
+            payload[20] = 1;
+            payload[21..25].copy_from_slice(&destination_tag.to_le_bytes());
+        }
+        // bytes 25..29 are reserved for 64-bit tags and must be 0
+
+        encode_base58_check(&version, &payload)
+    }
+    /// Decode an X-address e.g. "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC"
+    pub fn decode(address: &str) -> Result<Self, Error> {
+        let data = decode_base58_check(address, &[], 31)?;
+        let (version, payload) = data.split_at(2);
+        let is_test_network = match version {
+            v if v == X_ADDRESS_MAIN_NETWORK_VERSION => false,
+            v if v == X_ADDRESS_TEST_NETWORK_VERSION => true,
+            _ => return Err(Error::InvalidData("X-address version prefix".to_string())),
+        };
+        let tag = u32::from_le_bytes(payload[21..25].try_into().expect("it is 4 bytes"));
+        let destination_tag = match payload[20] {
+            0 if tag == 0 => None,
+            1 => Some(tag),
+            _ => return Err(Error::InvalidData("X-address tag flag".to_string())),
+        };
+        if payload[25..] != [0_u8; 4] {
+            return Err(Error::OutOfRange(
+                "X-address 64-bit tags are unsupported".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            account_id: AccountIdType(payload[..20].try_into().expect("it is 20 bytes")),
+            destination_tag,
+            is_test_network,
+        })
+    }
+}
+
+/// Base58Check encode `payload` with `version` prefix
+pub(crate) fn encode_base58_check(version: &[u8], payload: &[u8]) -> String {
+    let data = [version, payload].concat();
+    let checksum = checksum(&data);
+    encode_base58(&[data.as_slice(), checksum.as_slice()].concat())
+}
+
+/// Base58Check decode the `payload_length` byte payload of `encoded` with `version` prefix
+pub(crate) fn decode_base58_check(
+    encoded: &str,
+    version: &[u8],
+    payload_length: usize,
+) -> Result<Vec<u8>, Error> {
+    check_base58(&decode_base58(encoded)?, version, payload_length)
+}
+
+/// Check the length, checksum and `version` prefix of Base58Check decoded `data`, returning its payload
+fn check_base58(data: &[u8], version: &[u8], payload_length: usize) -> Result<Vec<u8>, Error> {
+    if data.len() != version.len() + payload_length + CHECKSUM_LENGTH {
+        return Err(Error::InvalidData(format!(
+            "base58 payload length: {}",
+            data.len().saturating_sub(version.len() + CHECKSUM_LENGTH)
+        )));
+    }
+    let (data, expected_checksum) = data.split_at(data.len() - CHECKSUM_LENGTH);
+    if checksum(data) != expected_checksum {
+        return Err(Error::InvalidData("base58 checksum mismatch".to_string()));
+    }
+    if !data.starts_with(version) {
+        return Err(Error::InvalidData("base58 version prefix".to_string()));
+    }
+
+    Ok(data[version.len()..].to_vec())
+}
+
+/// The first 4 bytes of the double SHA-256 of `data`
+fn checksum(data: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let digest = Sha256::digest(Sha256::digest(data));
+    digest[..CHECKSUM_LENGTH].try_into().expect("it is 4 bytes")
+}
+
+/// Encode `data` with the XRPL base58 alphabet
+fn encode_base58(data: &[u8]) -> String {
+    let leading_zeros = data.iter().take_while(|b| **b == 0).count();
+    // base58 digits, least significant first
+    let mut digits = Vec::<u8>::with_capacity(data.len() * 138 / 100 + 1);
+    for byte in &data[leading_zeros..] {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    core::iter::repeat_n(ALPHABET[0], leading_zeros)
+        .chain(digits.iter().rev().map(|d| ALPHABET[*d as usize]))
+        .map(char::from)
+        .collect()
+}
+
+/// Decode `encoded` with the XRPL base58 alphabet
+fn decode_base58(encoded: &str) -> Result<Vec<u8>, Error> {
+    let leading_zeros = encoded.bytes().take_while(|c| *c == ALPHABET[0]).count();
+    // bytes, least significant first
+    let mut bytes = Vec::<u8>::with_capacity(encoded.len() * 733 / 1000 + 1);
+    for c in encoded.bytes().skip(leading_zeros) {
+        let mut carry = ALPHABET
+            .iter()
+            .position(|a| *a == c)
+            .ok_or_else(|| Error::InvalidData(format!("base58 character: {}", c as char)))?
+            as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    bytes.extend(core::iter::repeat_n(0, leading_zeros));
+    bytes.reverse();
+
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn classic_address_round_trip() {
+        for (account_id, address) in [
+            ([0_u8; 20], "rrrrrrrrrrrrrrrrrrrrrhoLvTp"),
+            (
+                hex!("0000000000000000000000000000000000000001"),
+                "rrrrrrrrrrrrrrrrrrrrBZbvji",
+            ),
+            ([1_u8; 20], "raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC"),
+            (
+                hex!("B5F762798A53D543A014CAF8B297CFF8F2F937E8"),
+                "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+            ),
+        ] {
+            let account_id = AccountIdType(account_id);
+            assert_eq!(encode_classic_address(&account_id), address);
+            assert_eq!(decode_classic_address(address), Ok(account_id));
+        }
+    }
+
+    #[test]
+    fn decode_classic_address_invalid() {
+        // checksum
+        assert_eq!(
+            decode_classic_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTj"),
+            Err(Error::InvalidData("base58 checksum mismatch".to_string()))
+        );
+        // length
+        assert!(decode_classic_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdty").is_err());
+        assert_eq!(
+            decode_classic_address(&encode_base58_check(&ACCOUNT_ID_VERSION, &[1_u8; 21])),
+            Err(Error::InvalidData("base58 payload length: 21".to_string()))
+        );
+        // version
+        assert_eq!(
+            decode_classic_address(&encode_base58_check(&[0x01], &[1_u8; 20])),
+            Err(Error::InvalidData("base58 version prefix".to_string()))
+        );
+        // alphabet
+        assert_eq!(
+            decode_classic_address("0Hb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            Err(Error::InvalidData("base58 character: 0".to_string()))
+        );
+        assert!(decode_classic_address("").is_err());
+    }
+
+    #[test]
+    fn x_address_round_trip() {
+        // rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf
+        let account_id = AccountIdType(hex!("AA066C988C712815CC37AF71472B7CBBBD4E2A0A"));
+        for (destination_tag, is_test_network, address) in [
+            (
+                None,
+                false,
+                "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+            ),
+            (
+                Some(1),
+                false,
+                "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+            ),
+            (
+                None,
+                true,
+                "TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE",
+            ),
+            (
+                Some(u32::MAX),
+                true,
+                "TVE26TYGhfLC7tQDno7G8dGtxSkYQnXoy6kSDh6rZzApc69",
+            ),
+        ] {
+            let x_address = XAddress {
+                account_id: account_id.clone(),
+                destination_tag,
+                is_test_network,
+            };
+            assert_eq!(x_address.encode(), address);
+            assert_eq!(XAddress::decode(address), Ok(x_address));
+        }
+    }
+
+    #[test]
+    fn decode_x_address_invalid() {
+        let payload = |flag: u8, high_tag: u8| {
+            let mut payload = [0_u8; 29];
+            payload[20] = flag;
+            payload[25] = high_tag;
+            payload
+        };
+        assert_eq!(
+            XAddress::decode(&encode_base58_check(&[0x05, 0x45], &payload(0, 0))),
+            Err(Error::InvalidData("X-address version prefix".to_string()))
+        );
+        assert_eq!(
+            XAddress::decode(&encode_base58_check(
+                &X_ADDRESS_MAIN_NETWORK_VERSION,
+                &payload(2, 0)
+            )),
+            Err(Error::InvalidData("X-address tag flag".to_string()))
+        );
+        assert!(XAddress::decode(&encode_base58_check(
+            &X_ADDRESS_MAIN_NETWORK_VERSION,
+            &payload(1, 1)
+        ))
+        .is_err());
+        // a classic address
+        assert!(XAddress::decode("rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf").is_err());
+    }
+
+    #[test]
+    fn seed_round_trip() {
+        for (seed, key_type, encoded) in [
+            (
+                hex!("CF2DE378FBDD7E2EE87D486DFB5A7BFF"),
+                KeyType::Secp256k1,
+                "sn259rEFXrQrWyx3Q7XneWcwV6dfL",
+            ),
+            (
+                hex!("DEDCE9CE67B451D852FD4E846FCDE31C"),
+                KeyType::Secp256k1,
+                "snoPBrXtMeMyMHUVTgbuqAfg1SUTb",
+            ),
+            (
+                hex!("4C3A1D213FBDFB14C7C28D609469B341"),
+                KeyType::Ed25519,
+                "sEdTM1uX8pu2do5XvTnutH6HsouMaM2",
+            ),
+        ] {
+            assert_eq!(encode_seed(&seed, key_type), encoded);
+            assert_eq!(decode_seed(encoded), Ok((seed, key_type)));
+        }
+        assert!(decode_seed("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").is_err());
+        // corrupted ed25519 seeds fail as such
+        assert_eq!(
+            decode_seed("sEdTM1uX8pu2do5XvTnutH6HsouMaM3"),
+            Err(Error::InvalidData("base58 checksum mismatch".to_string()))
+        );
+        assert_eq!(
+            decode_seed(&encode_base58_check(&[0x01, 0xe1, 0x4c], &[1_u8; 16])),
+            Err(Error::InvalidData("base58 version prefix".to_string()))
+        );
+    }
+
+    #[test]
+    fn public_key_round_trip() {
+        let node_public_key = PublicKey::try_from(hex!(
+            "0388E5BA87A000CB807240DF8C848EB0B5FFA5C8E5A521BC8E105C0F0A44217828"
+        ))
+        .unwrap();
+        let encoded = "n9MXXueo837zYH36DvMc13BwHcqtfAWNJY5czWVbp7uYTj7x17TH";
+        assert_eq!(encode_node_public_key(&node_public_key), encoded);
+        assert_eq!(decode_node_public_key(encoded), Ok(node_public_key));
+        assert!(decode_account_public_key(encoded).is_err());
+
+        let account_public_key = PublicKey::try_from(hex!(
+            "023693F15967AE357D0327974AD46FE3C127113B1110D6044FD41E723689F81CC6"
+        ))
+        .unwrap();
+        let encoded = "aB44YfzW24VDEJQ2UuLPV2PvqcPCSoLnL7y5M1EzhdW4LnK5xMS3";
+        assert_eq!(encode_account_public_key(&account_public_key), encoded);
+        assert_eq!(decode_account_public_key(encoded), Ok(account_public_key));
+        assert!(decode_node_public_key(encoded).is_err());
+
+        let account_public_key = PublicKey::try_from(hex!(
+            "EDAAC3F98BB94F451804EF5993C847DAAA4E6154F455635659D88AA5C80F156303"
+        ))
+        .unwrap();
+        let encoded = "aKGheSBjmCsKJVuLNKRAKpZXT6wpk2FCuEZAXJupXgdAxX5THCqR";
+        assert_eq!(encode_account_public_key(&account_public_key), encoded);
+        assert_eq!(decode_account_public_key(encoded), Ok(account_public_key));
+    }
+}
@@ -0,0 +1,82 @@
+//! XRPL field definitions registry
+//!
+//! A const lookup table generated from 'definitions.json' at compile time
+use crate::field::FieldId;
+
+/// Metadata about a codec field as given by 'definitions.json'
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldDefinition {
+    /// The field name e.g. "Account"
+    pub name: &'static str,
+    /// The field code (may be negative for special fields e.g. "Invalid")
+    pub nth: i16,
+    /// The type name e.g. "AccountID"
+    pub type_name: &'static str,
+    /// The type code (may be negative for special types e.g. "Unknown")
+    pub type_code: i16,
+    pub is_vl_encoded: bool,
+    pub is_serialized: bool,
+    pub is_signing_field: bool,
+}
+
+impl FieldDefinition {
+    /// The ID of this field in a serialized object
+    ///
+    /// Only meaningful for serialized fields
+    pub const fn field_id(&self) -> FieldId {
+        FieldId::new(self.type_code as u16, self.nth as u16)
+    }
+}
+
+/// Path of the alternative definitions the codec was compiled against (if any)
+///
+/// Set `XRPL_CODEC_DEFINITIONS` at build time to compile the codec (fields, transaction types and this registry)
+/// against the definitions of a private network or sidechain. Reading it here also rebuilds the codec when it changes
+pub const DEFINITIONS_PATH: Option<&str> = option_env!("XRPL_CODEC_DEFINITIONS");
+
+/// All fields from 'definitions.json' in file order
+pub const FIELDS: &[FieldDefinition] = &xrpl_codec_utils::field_definitions!();
+
+/// Lookup a serialized field by its ID
+pub fn field_by_id(field_id: FieldId) -> Option<&'static FieldDefinition> {
+    FIELDS
+        .iter()
+        .find(|f| f.is_serialized && f.field_id() == field_id)
+}
+
+/// Lookup a field by its name e.g. "Account"
+pub fn field_by_name(name: &str) -> Option<&'static FieldDefinition> {
+    FIELDS.iter().find(|f| f.name == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ACCOUNT_ID_TYPE_CODE, BLOB_TYPE_CODE};
+
+    #[test]
+    fn lookup_field() {
+        let account = field_by_id(FieldId::new(ACCOUNT_ID_TYPE_CODE, 1)).unwrap();
+        assert_eq!(account.name, "Account");
+        assert_eq!(account.type_name, "AccountID");
+        assert!(account.is_vl_encoded && account.is_serialized && account.is_signing_field);
+        assert_eq!(field_by_name("Account"), Some(account));
+
+        let txn_signature = field_by_name("TxnSignature").unwrap();
+        assert_eq!(txn_signature.field_id(), FieldId::new(BLOB_TYPE_CODE, 4));
+        assert!(txn_signature.is_serialized && !txn_signature.is_signing_field);
+
+        let invalid = field_by_name("Invalid").unwrap();
+        assert_eq!((invalid.nth, invalid.type_code), (-1, -2));
+        assert!(!invalid.is_serialized);
+    }
+
+    #[test]
+    fn lookup_unknown_field() {
+        assert_eq!(field_by_name("NotAField"), None);
+        assert_eq!(field_by_id(FieldId::new(2, 200)), None);
+        // "hash" is not serialized
+        let hash = field_by_name("hash").unwrap();
+        assert_eq!(field_by_id(hash.field_id()), None);
+    }
+}
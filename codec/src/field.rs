@@ -1,213 +1,277 @@
 //! XRPL codec fields
 //! These are higher-level types which typically wrap a primitive value
 
-use xrpl_codec_utils::Field;
+use xrpl_codec_utils::{codec_fields, transaction_type_codes, Field};
 
-use crate::types::Hash256Type;
 use crate::{
-    traits::{BinarySerialize, CodecField},
+    traits::{BinaryDeserialize, BinarySerialize, CodecField},
     types::{
-        AccountIdType, AmountType, BlobType, STArrayType, SignerEntryType, UInt16Type, UInt32Type,
-        ACCOUNT_ID_TYPE_CODE,
+        AccountIdType, AmountType, BlobType, CurrencyType, Hash128Type, Hash160Type, Hash256Type,
+        IssueType, PathSetType, STArrayItemType, STArrayType, STObjectType, SignerEntryType,
+        SignerType, UInt16Type, UInt192Type, UInt32Type, UInt64Type, UInt8Type, Vector256Type,
+        XChainBridgeType,
     },
-    Vec,
+    Error, Vec,
 };
+use alloc::{format, string::ToString};
+
+// Every (supported) field in 'definitions.json'
+codec_fields! {
+    SignerEntry(SignerEntryType);
+    SignerEntries(STArrayType<SignerEntry>);
+    Signer(SignerType);
+    Signers(STArrayType<Signer>);
+}
 
-// TODO: auto-generate the structs from definitions.json
-
-#[derive(Field, Debug, Clone)]
-pub struct Account(pub AccountIdType);
-
-#[derive(Field, Debug, Clone)]
-pub struct Destination(pub AccountIdType);
-
-#[derive(Field, Debug, Clone)]
-pub struct TransactionType(pub UInt16Type);
 impl From<TransactionTypeCode> for TransactionType {
     fn from(v: TransactionTypeCode) -> Self {
         TransactionType(UInt16Type(v.code()))
     }
 }
 
-#[derive(Field, Debug, Clone)]
-pub struct Fee(pub AmountType);
-
-#[derive(Field, Debug, Clone)]
-pub struct Flags(pub UInt32Type);
-
-#[derive(Field, Debug, Clone)]
-pub struct Sequence(pub UInt32Type);
-
-#[derive(Field, Debug, Clone)]
-pub struct SourceTag(pub UInt32Type);
-
-#[derive(Field, Debug, Clone)]
-pub struct DestinationTag(pub UInt32Type);
-
-#[derive(Field, Debug, Clone)]
-pub struct TicketSequence(pub UInt32Type);
-
-#[derive(Field, Debug, Default)]
-pub struct SigningPubKey(pub BlobType);
-
-#[derive(Field, Debug, Clone)]
-pub struct Amount(pub AmountType);
-
-#[derive(Field, Debug, Default)]
-pub struct TxnSignature(pub BlobType);
+// n.b. `PublicKey` is also a field
+impl From<crate::public_key::PublicKey> for SigningPubKey {
+    fn from(v: crate::public_key::PublicKey) -> Self {
+        SigningPubKey(BlobType(v.to_bytes().to_vec()))
+    }
+}
 
-#[derive(Field, Debug, Clone)]
-pub struct SignerQuorum(pub UInt32Type);
+impl<T: CodecField> BinarySerialize for T {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        if !self.is_serialized() {
+            return Ok(());
+        }
 
-#[derive(Field, Debug, Clone)]
-pub struct SignerWeight(pub UInt16Type);
+        if for_signing && !self.is_signing_field() {
+            return Ok(());
+        }
 
-#[derive(Field, Debug, Clone)]
-pub struct SignerEntry(pub SignerEntryType);
+        // header
+        self.field_id().encode(buf);
 
-#[derive(Field, Debug, Clone)]
-pub struct SignerEntries(pub STArrayType<SignerEntry>);
+        if !self.is_variable_length() {
+            return self.inner().binary_serialize_to(buf, for_signing);
+        }
 
-#[derive(Field, Debug, Clone)]
-pub struct NFTokenID(pub Hash256Type);
+        // variable length prefixed type
+        // AccountID length prefix is always 0x14 i.e. 20 bytes
+        // https://xrpl.org/serialization.html#accountid-fields
+        let mut data = self.inner().binary_serialize(for_signing)?;
+        buf.extend_from_slice(&encode_vl_length(data.len())?);
+        buf.append(&mut data);
+        Ok(())
+    }
+}
 
-#[derive(Field, Debug, Clone)]
-pub struct NFTokenSellOffer(pub Hash256Type);
+/// Encode the length prefix of a variable length field with `length` bytes of data
+/// https://xrpl.org/serialization.html#length-prefixing
+///
+/// Returns the 1, 2 or 3 byte prefix or an error if `length` exceeds the max. of 918,744 bytes
+pub fn encode_vl_length(length: usize) -> Result<Vec<u8>, Error> {
+    // https://github.com/XRPLF/xrpl.js/blob/8a9a9bcc28ace65cde46eed5010eb8927374a736/packages/ripple-binary-codec/src/serdes/binary-serializer.ts#L103
+    match length {
+        0..=192 => Ok([length as u8].to_vec()),
+        193..=12_480 => {
+            // 193 + ((a - 193) * 256) + b
+            let length = length - 193;
+            Ok([193 + (length >> 8) as u8, length as u8].to_vec())
+        }
+        12_481..=918_744 => {
+            // 12_481 + ((a - 241) * 65_536) + (b * 256) + c
+            let length = length - 12_481;
+            Ok([
+                241 + (length >> 16) as u8,
+                (length >> 8) as u8,
+                length as u8,
+            ]
+            .to_vec())
+        }
+        _ => Err(Error::OutOfRange(format!(
+            "variable length data too long: {} bytes",
+            length
+        ))),
+    }
+}
 
-impl<T: CodecField> BinarySerialize for T {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) {
-        if !self.is_serialized() {
-            return;
+/// Decode the length prefix of a variable length field from the front of `data`
+/// https://xrpl.org/serialization.html#length-prefixing
+///
+/// Returns the (length, # of prefix bytes consumed)
+pub fn decode_vl_length(data: &[u8]) -> Result<(usize, usize), Error> {
+    let mut buf = data;
+    let length_a = u8::binary_deserialize_from(&mut buf)? as usize;
+    match length_a {
+        0..=192 => Ok((length_a, 1)),
+        193..=240 => {
+            let length_b = u8::binary_deserialize_from(&mut buf)? as usize;
+            Ok((193 + ((length_a - 193) * 256) + length_b, 2))
         }
+        241..=254 => {
+            let [length_b, length_c] = <[u8; 2]>::binary_deserialize_from(&mut buf)?;
+            Ok((
+                12_481
+                    + ((length_a - 241) * 65_536)
+                    + (length_b as usize * 256)
+                    + length_c as usize,
+                3,
+            ))
+        }
+        _ => Err(Error::InvalidData(format!(
+            "invalid length prefix: {:#04x}",
+            length_a
+        ))),
+    }
+}
 
-        if for_signing && !self.is_signing_field() {
-            return;
+/// Identifies a field by its type and field code, encoded as the field header
+/// https://xrpl.org/serialization.html#field-ids
+///
+/// The derived ordering is the canonical field order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "scale",
+    derive(
+        codec::Encode,
+        codec::Decode,
+        scale_info::TypeInfo,
+        codec::MaxEncodedLen
+    )
+)]
+pub struct FieldId {
+    /// The XRPL type code of the field's underlying (primitive) type, 1..=255
+    pub type_code: u16,
+    /// The XRPL field code (aka 'nth' in 'definitions.json'), 1..=255
+    pub field_code: u16,
+}
+
+impl FieldId {
+    pub const fn new(type_code: u16, field_code: u16) -> Self {
+        Self {
+            type_code,
+            field_code,
         }
+    }
 
-        // header
-        if self.type_code() < 16 {
-            if self.field_code() < 16 {
-                buf.push(((self.type_code() << 4) | self.field_code()) as u8);
+    /// Encode the field header into `buf`, using the shortest of the 1, 2 or 3 byte layouts
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        if self.type_code < 16 {
+            if self.field_code < 16 {
+                buf.push(((self.type_code << 4) | self.field_code) as u8);
             } else {
-                buf.push((self.type_code() << 4) as u8);
-                buf.push(self.field_code() as u8);
+                buf.push((self.type_code << 4) as u8);
+                buf.push(self.field_code as u8);
             }
-        } else if self.type_code() >= 16 && self.field_code() < 16 {
-            buf.push(self.field_code() as u8);
-            buf.push(self.type_code() as u8);
+        } else if self.type_code >= 16 && self.field_code < 16 {
+            buf.push(self.field_code as u8);
+            buf.push(self.type_code as u8);
         } else {
-            // self.type_code() >= 16 && self.field_code() >= 16
+            // self.type_code >= 16 && self.field_code >= 16
             buf.push(0_u8);
-            buf.push(self.type_code() as u8);
-            buf.push(self.field_code() as u8);
-        }
-
-        if !self.is_variable_length() {
-            self.inner().binary_serialize_to(buf, for_signing);
-            return;
+            buf.push(self.type_code as u8);
+            buf.push(self.field_code as u8);
         }
+    }
 
-        // variable length prefixed type
-        let mut data = self.inner().binary_serialize(for_signing);
-        // AccountID length prefix is always 0x14
-        // https://xrpl.org/serialization.html#accountid-fields
-        if self.type_code() == ACCOUNT_ID_TYPE_CODE {
-            buf.push(0x14);
-        } else {
-            // https://github.com/XRPLF/xrpl.js/blob/8a9a9bcc28ace65cde46eed5010eb8927374a736/packages/ripple-binary-codec/src/serdes/binary-serializer.ts#L103
-            // https://xrpl.org/serialization.html#length-prefixing
-            // length prefix
-            match data.len() {
-                0..=192 => buf.push(data.len() as u8),
-                193..=12_480 => {
-                    // 193 + ((a - 193) * 256) + b
-                    let [length_a, length_b] = ((data.len() - 193) as u16).to_be_bytes();
-                    buf.push(length_a + 193_u8);
-                    buf.push(length_b as u8);
-                }
-                12_481..=918_744 => {
-                    // 12_481 + ((a - 241) * 65_536) + (b * 256) + c
-                    let [length_a, length_b, length_c, _length_d] =
-                        ((data.len() - 12_841) as u32).to_be_bytes();
-                    buf.push(241 + length_a);
-                    buf.push(length_b);
-                    buf.push(length_c);
-                }
-                _ => panic!("data too long"),
+    /// Decode a field header from the front of `buf`
+    ///
+    /// Fails on truncated input or a non-canonical header i.e. one that is not the shortest layout for the codes
+    pub fn decode(buf: &mut &[u8]) -> Result<Self, Error> {
+        let first = u8::binary_deserialize_from(buf)?;
+        let (type_code, field_code) = match (first >> 4, first & 0x0f) {
+            // 1 byte: type code < 16, field code < 16
+            (type_code @ 1.., field_code @ 1..) => (type_code, field_code),
+            // 2 bytes: type code < 16, field code >= 16
+            (type_code @ 1.., 0) => (type_code, u8::binary_deserialize_from(buf)?),
+            // 2 bytes: type code >= 16, field code < 16
+            (0, field_code @ 1..) => (u8::binary_deserialize_from(buf)?, field_code),
+            // 3 bytes: type code >= 16, field code >= 16
+            (0, 0) => {
+                let [type_code, field_code] = <[u8; 2]>::binary_deserialize_from(buf)?;
+                (type_code, field_code)
             }
+        };
+
+        let field_id = Self::new(type_code as u16, field_code as u16);
+        if (first >> 4 == 0 && type_code < 16) || (first & 0x0f == 0 && field_code < 16) {
+            return Err(Error::InvalidData(format!(
+                "non-canonical field header: {:?}",
+                field_id
+            )));
         }
-        buf.append(&mut data);
+
+        Ok(field_id)
     }
 }
 
-/// XRPL TransactionTypes
-pub enum TransactionTypeCode {
-    // Invalid = -1,
-    Payment = 0,
-    EscrowCreate = 1,
-    EscrowFinish = 2,
-    AccountSet = 3,
-    EscrowCancel = 4,
-    SetRegularKey = 5,
-    NickNameSet = 6,
-    OfferCreate = 7,
-    OfferCancel = 8,
-    Contract = 9,
-    TicketCreate = 10,
-    TicketCancel = 11,
-    SignerListSet = 12,
-    PaymentChannelCreate = 13,
-    PaymentChannelFund = 14,
-    PaymentChannelClaim = 15,
-    CheckCreate = 16,
-    CheckCash = 17,
-    CheckCancel = 18,
-    DepositPreauth = 19,
-    TrustSet = 20,
-    AccountDelete = 21,
-    SetHook = 22,
-    NFTokenMint = 25,
-    NFTokenBurn = 26,
-    NFTokenCreateOffer = 27,
-    NFTokenCancelOffer = 28,
-    NFTokenAcceptOffer = 29,
-    EnableAmendment = 100,
-    SetFee = 101,
-    UNLModify = 102,
-}
+/// Decode the value of the field identified by `type_code` and `field_code` from the front of `buf`
+///
+/// Used by the `Field` derive (including for fields of downstream crates)
+pub fn decode_field_value<T: BinaryDeserialize>(
+    buf: &mut &[u8],
+    type_code: u16,
+    field_code: u16,
+    is_variable_length: bool,
+) -> Result<T, Error> {
+    let expected = FieldId::new(type_code, field_code);
+    let field_id = FieldId::decode(buf)?;
+    if field_id != expected {
+        return Err(Error::InvalidData(format!(
+            "expected field {:?}, found {:?}",
+            expected, field_id
+        )));
+    }
+
+    if !is_variable_length {
+        return T::binary_deserialize_from(buf);
+    }
 
-impl TransactionTypeCode {
-    pub fn code(self) -> u16 {
-        self as u16
+    let (length, consumed) = decode_vl_length(buf)?;
+    *buf = &buf[consumed..];
+    if buf.len() < length {
+        return Err(Error::InvalidData("unexpected end of input".to_string()));
     }
+    let (data, rest) = buf.split_at(length);
+    *buf = rest;
+
+    T::binary_deserialize(data)
 }
 
+transaction_type_codes!();
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{BlobType, CurrencyCodeType, IssuedAmountType, IssuedValueType};
+    use crate::types::{
+        BlobType, CurrencyCodeType, IssuedAmountType, IssuedValueType, STObject, SerializedValue,
+    };
 
     #[test]
     fn serialize_signing_pub_key() {
-        let buf = SigningPubKey(BlobType(vec![1_u8; 65])).binary_serialize(true);
+        let buf = SigningPubKey(BlobType(vec![1_u8; 65]))
+            .binary_serialize(true)
+            .unwrap();
         println!("{:?}", hex::encode(&buf));
     }
     #[test]
     fn serialize_transaction_type() {
         let tt: TransactionType = TransactionTypeCode::Payment.into();
-        let buf = tt.binary_serialize(true);
+        let buf = tt.binary_serialize(true).unwrap();
         println!("{:?}", hex::encode(&buf));
     }
     #[test]
     fn serialize_account() {
         let account = [1_u8; 20];
-        let buf = Account(AccountIdType(account)).binary_serialize(true);
+        let buf = Account(AccountIdType(account))
+            .binary_serialize(true)
+            .unwrap();
         println!("{:?}", hex::encode(&buf));
     }
     #[test]
     fn serialize_destination() {
         let dest = [1_u8; 20];
-        let buf = Destination(AccountIdType(dest)).binary_serialize(true);
+        let buf = Destination(AccountIdType(dest))
+            .binary_serialize(true)
+            .unwrap();
         println!("{:?}", hex::encode(&buf));
     }
     #[test]
@@ -216,7 +280,7 @@ mod tests {
             Account(AccountIdType([1_u8; 20])),
             SignerWeight(UInt16Type(1_u16)),
         ));
-        let buf = signer_entry.binary_serialize(true);
+        let buf = signer_entry.binary_serialize(true).unwrap();
         // construct the expected buffer manually
         let signer_entry_field_id: u8 = 0xEB; // Typecode(14) | FieldCode(11) = 0xEB
         let account_field_id: u8 = 0x81; // Typecode(8) | Fieldcode(1) = 0x81(129)
@@ -246,7 +310,7 @@ mod tests {
         }
         let signer_entries = SignerEntries(STArrayType(signer_entries_vec));
 
-        let buf = signer_entries.binary_serialize(true);
+        let buf = signer_entries.binary_serialize(true).unwrap();
         let signer_entries_field_id: u8 = 0xF4; // Typecode(15) | FieldCode(4) = 0xF4
         let signer_entry_field_id: u8 = 0xEB; // Typecode(14) | FieldCode(11) = 0xEB
         let account_field_id: u8 = 0x81; // Typecode(8) | Fieldcode(1) = 0x81(129)
@@ -273,31 +337,39 @@ mod tests {
     #[test]
     fn serialize_sequence() {
         let nonce = 17_u32;
-        let buf = Sequence(UInt32Type(nonce)).binary_serialize(true);
+        let buf = Sequence(UInt32Type(nonce)).binary_serialize(true).unwrap();
         println!("{:?}", hex::encode(&buf));
     }
     #[test]
     fn serialize_ticket_sequence() {
         let ticket_number = 1_u32;
-        let buf = TicketSequence(UInt32Type(ticket_number)).binary_serialize(true);
+        let buf = TicketSequence(UInt32Type(ticket_number))
+            .binary_serialize(true)
+            .unwrap();
         println!("{:?}", hex::encode(&buf));
     }
     #[test]
     fn serialize_source_tag() {
         let source_tag = 38_887_387_u32;
-        let buf = SourceTag(UInt32Type(source_tag)).binary_serialize(true);
+        let buf = SourceTag(UInt32Type(source_tag))
+            .binary_serialize(true)
+            .unwrap();
         println!("{:?}", hex::encode(&buf));
     }
     #[test]
     fn serialize_destinatiom_tag() {
         let destination_tag = 18_887_987_u32;
-        let buf = DestinationTag(UInt32Type(destination_tag)).binary_serialize(true);
+        let buf = DestinationTag(UInt32Type(destination_tag))
+            .binary_serialize(true)
+            .unwrap();
         println!("{:?}", hex::encode(&buf));
     }
     #[test]
     fn serialize_amount_drops() {
         let amount = 10;
-        let buf = Amount(AmountType::Drops(amount)).binary_serialize(true);
+        let buf = Amount(AmountType::Drops(amount))
+            .binary_serialize(true)
+            .unwrap();
         println!("{:?}", hex::encode(&buf));
         assert_eq!(buf.as_slice(), hex::decode("61400000000000000a").unwrap());
     }
@@ -313,8 +385,161 @@ mod tests {
             AccountIdType(issuer),
         )
         .unwrap();
-        let buf = Amount(AmountType::Issued(issued_amount)).binary_serialize(true);
+        let buf = Amount(AmountType::Issued(issued_amount))
+            .binary_serialize(true)
+            .unwrap();
         println!("{:?}", hex::encode(&buf));
         assert_eq!(buf.as_slice(), hex::decode("61d491c37937e0800000000000000000000000000041534100000000000303030303030303030303030303030303030303").unwrap());
     }
+    #[test]
+    fn field_id_layouts() {
+        for (field_id, encoded) in [
+            (FieldId::new(1, 3), vec![0x13]),
+            (FieldId::new(2, 26), vec![0x20, 0x1a]),
+            (FieldId::new(16, 2), vec![0x02, 0x10]),
+            (FieldId::new(17, 17), vec![0x00, 0x11, 0x11]),
+        ] {
+            let mut buf = Vec::<u8>::default();
+            field_id.encode(&mut buf);
+            assert_eq!(buf, encoded);
+            let input = [encoded.as_slice(), &[0xff]].concat();
+            let mut cursor = input.as_slice();
+            assert_eq!(FieldId::decode(&mut cursor), Ok(field_id));
+            // only the header is consumed
+            assert_eq!(cursor, &[0xff]);
+        }
+    }
+    #[test]
+    fn field_id_non_canonical() {
+        // field code < 16 in the 2 byte layout
+        assert!(FieldId::decode(&mut [0x20_u8, 0x01].as_slice()).is_err());
+        // type code < 16 in the 2 byte layout
+        assert!(FieldId::decode(&mut [0x02_u8, 0x01].as_slice()).is_err());
+        // type and field code < 16 in the 3 byte layout
+        assert!(FieldId::decode(&mut [0x00_u8, 0x01, 0x11].as_slice()).is_err());
+        assert!(FieldId::decode(&mut [0x00_u8, 0x11, 0x01].as_slice()).is_err());
+        // truncated
+        assert!(FieldId::decode(&mut [0x00_u8, 0x11].as_slice()).is_err());
+        assert!(FieldId::decode(&mut [].as_slice()).is_err());
+    }
+    #[test]
+    fn vl_length_boundaries() {
+        for (length, encoded) in [
+            (0_usize, vec![0x00]),
+            (192, vec![0xc0]),
+            (193, vec![0xc1, 0x00]),
+            (12_480, vec![0xf0, 0xff]),
+            (12_481, vec![0xf1, 0x00, 0x00]),
+            (918_744, vec![0xfe, 0xd4, 0x17]),
+        ] {
+            assert_eq!(encode_vl_length(length), Ok(encoded.clone()));
+            assert_eq!(decode_vl_length(&encoded), Ok((length, encoded.len())));
+        }
+        assert!(encode_vl_length(918_745).is_err());
+    }
+    #[test]
+    fn vl_length_invalid() {
+        // reserved first byte
+        assert!(decode_vl_length(&[0xff, 0x00, 0x00]).is_err());
+        // truncated
+        assert!(decode_vl_length(&[]).is_err());
+        assert!(decode_vl_length(&[0xc1]).is_err());
+        assert!(decode_vl_length(&[0xf1, 0x00]).is_err());
+        // only the prefix is consumed
+        assert_eq!(decode_vl_length(&[0x01, 0xff]), Ok((1, 1)));
+    }
+    #[test]
+    fn serialize_field_too_long() {
+        let signing_pub_key = SigningPubKey(BlobType(vec![1_u8; 918_745]));
+        assert!(signing_pub_key.binary_serialize(true).is_err());
+    }
+    #[test]
+    fn deserialize_fields() {
+        let account = Account(AccountIdType([1_u8; 20]));
+        let buf = account.binary_serialize(true).unwrap();
+        assert_eq!(Account::binary_deserialize(&buf), Ok(account));
+
+        let signing_pub_key = SigningPubKey(BlobType(vec![1_u8; 33]));
+        let buf = signing_pub_key.binary_serialize(true).unwrap();
+        assert_eq!(SigningPubKey::binary_deserialize(&buf), Ok(signing_pub_key));
+
+        let amount = Amount(AmountType::Drops(10));
+        let buf = amount.binary_serialize(true).unwrap();
+        assert_eq!(Amount::binary_deserialize(&buf), Ok(amount));
+
+        let signer_entries = SignerEntries(STArrayType(vec![SignerEntry(SignerEntryType(
+            Account(AccountIdType([1_u8; 20])),
+            SignerWeight(UInt16Type(1_u16)),
+        ))]));
+        let buf = signer_entries.binary_serialize(true).unwrap();
+        assert_eq!(SignerEntries::binary_deserialize(&buf), Ok(signer_entries));
+    }
+    #[test]
+    fn deserialize_field_header_mismatch() {
+        let buf = Destination(AccountIdType([1_u8; 20]))
+            .binary_serialize(true)
+            .unwrap();
+        assert!(Account::binary_deserialize(&buf).is_err());
+    }
+    #[test]
+    fn deserialize_field_length_prefix() {
+        // 2 and 3 byte length prefixes
+        for length in [12_480, 918_744] {
+            let signing_pub_key = SigningPubKey(BlobType(vec![1_u8; length]));
+            let buf = signing_pub_key.binary_serialize(true).unwrap();
+            assert_eq!(SigningPubKey::binary_deserialize(&buf), Ok(signing_pub_key));
+        }
+        // length prefix exceeds the available data
+        let buf = SigningPubKey(BlobType(vec![1_u8; 33]))
+            .binary_serialize(true)
+            .unwrap();
+        assert!(SigningPubKey::binary_deserialize(&buf[..buf.len() - 1]).is_err());
+        // AccountID length prefix must be 20
+        let mut buf = Account(AccountIdType([1_u8; 20]))
+            .binary_serialize(true)
+            .unwrap();
+        buf[1] = 0x13;
+        assert!(Account::binary_deserialize(&buf).is_err());
+    }
+    #[test]
+    fn generated_fields() {
+        let mut memo = STObject::new();
+        memo.insert(
+            MemoData(BlobType::default()).field_id(),
+            SerializedValue::Blob(BlobType(vec![0xab, 0xcd])),
+        )
+        .unwrap();
+        let memos = Memos(STArrayType(vec![STArrayItemType(
+            Memo(STObjectType::default()).field_id(),
+            STObjectType(memo),
+        )]));
+        let buf = memos.binary_serialize(true).unwrap();
+        // Memos | Memo | MemoData, VL 2 | 0xabcd | object end | array end
+        assert_eq!(buf, [0xf9, 0xea, 0x7d, 0x02, 0xab, 0xcd, 0xe1, 0xf1]);
+        assert_eq!(Memos::binary_deserialize(&buf), Ok(memos));
+
+        // only inner objects may be array items
+        assert!(Memos::binary_deserialize(&[0xf9, 0x7d, 0x02, 0xab, 0xcd, 0xe1, 0xf1]).is_err());
+
+        let last_ledger_sequence = LastLedgerSequence(UInt32Type(1));
+        assert_eq!(last_ledger_sequence.field_id(), FieldId::new(2, 27));
+        assert!(last_ledger_sequence.is_signing_field());
+        assert!(!Signature(BlobType::default()).is_signing_field());
+    }
+    #[test]
+    fn transaction_type_codes() {
+        assert_eq!(TransactionTypeCode::SignerListSet.code(), 12);
+        assert_eq!(TransactionTypeCode::SignerListSet.name(), "SignerListSet");
+        assert_eq!(
+            TransactionTypeCode::from_code(102),
+            Some(TransactionTypeCode::UNLModify)
+        );
+        // unassigned
+        assert_eq!(TransactionTypeCode::from_code(23), None);
+        assert_eq!(
+            TransactionTypeCode::from_name("Payment"),
+            Some(TransactionTypeCode::Payment)
+        );
+        assert_eq!(TransactionTypeCode::from_name("payment"), None);
+    }
 }
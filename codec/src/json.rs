@@ -0,0 +1,201 @@
+//! Conversion between the XRPL binary format and its canonical JSON form i.e. as `xrpl.encode`/`xrpl.decode`
+//!
+//! Fields are keyed by their 'definitions.json' name, XRP amounts are drop strings and token amounts
+//! `{currency, issuer, value}` objects, account IDs are classic addresses and binary values are uppercase hex
+use alloc::string::ToString;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    traits::{BinaryDeserialize, BinarySerialize},
+    types::STObject,
+    Error, Vec,
+};
+
+/// Decode a binary serialized object (e.g. a transaction) into JSON
+pub fn decode(binary: &[u8]) -> Result<Value, Error> {
+    object_to_json(&STObject::binary_deserialize(binary)?)
+}
+
+/// Binary serialize a JSON object (e.g. a transaction)
+///
+/// To serialize for signing use `object_from_json` with `utils::encode_for_signing`
+pub fn encode(json: &Value) -> Result<Vec<u8>, Error> {
+    object_from_json(json)?.binary_serialize(false)
+}
+
+/// Convert `object` into JSON
+pub fn object_to_json(object: &STObject) -> Result<Value, Error> {
+    serde_json::to_value(object).map_err(|err| Error::InvalidData(err.to_string()))
+}
+
+/// Convert a JSON object into an `STObject`
+pub fn object_from_json(json: &Value) -> Result<STObject, Error> {
+    STObject::deserialize(json).map_err(|err| Error::InvalidData(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    use crate::{
+        field::Amount,
+        public_key::PublicKey,
+        signature::DerSignature,
+        transaction::{PaymentAltCurrency, SignerListSet},
+        types::*,
+    };
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_PaymentAltCurrency_json() {
+        let amount = IssuedAmountType::from_issued_value(
+            IssuedValueType::from_mantissa_exponent(314, -2).unwrap(),
+            CurrencyCodeType::Standard(*b"AST"),
+            AccountIdType([3_u8; 20]),
+        )
+        .unwrap();
+        let mut payment = PaymentAltCurrency::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            Amount(AmountType::Issued(amount)),
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        payment
+            .attach_signature(DerSignature::try_from([7_u8; 65]).unwrap())
+            .unwrap();
+        let encoded = payment.binary_serialize(false).unwrap();
+
+        // as `xrpl.decode`
+        let expected = json!({
+            "TransactionType": "Payment",
+            "Flags": 2147483648_u32,
+            "SourceTag": 38887387,
+            "Sequence": 1,
+            "TicketSequence": 1,
+            "Amount": {
+                "value": "3.14",
+                "currency": "AST",
+                "issuer": "rGvdqXNwMbSwRiubF4PhhVqzhkiaTDPgU"
+            },
+            "Fee": "1000",
+            "SigningPubKey": "020202020202020202020202020202020202020202020202020202020202020202",
+            "TxnSignature": "30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707",
+            "Account": "raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC",
+            "Destination": "rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm"
+        });
+        assert_eq!(decode(&encoded), Ok(expected.clone()));
+        assert_eq!(encode(&expected), Ok(encoded));
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_SignerListSet_json() {
+        let signer_list_set = SignerListSet::new(
+            [1_u8; 20],
+            1_000,
+            1_u32,
+            1_u32,
+            2_u32,
+            vec![([2_u8; 20], 1_u16), ([3_u8; 20], 1_u16)],
+            38_887_387_u32,
+            None,
+        );
+        let encoded = signer_list_set.binary_serialize(false).unwrap();
+        let json = decode(&encoded).unwrap();
+
+        assert_eq!(json["TransactionType"], "SignerListSet");
+        assert_eq!(json["SigningPubKey"], "");
+        assert_eq!(
+            json["SignerEntries"][1],
+            json!({
+                "SignerEntry": {
+                    "Account": "rGvdqXNwMbSwRiubF4PhhVqzhkiaTDPgU",
+                    "SignerWeight": 1
+                }
+            })
+        );
+        assert_eq!(encode(&json), Ok(encoded));
+    }
+
+    #[test]
+    fn round_trip_types() {
+        let json = json!({
+            "TransactionType": "AMMCreate",
+            "OwnerNode": "00000000000000FF",
+            "MaximumAmount": "1000",
+            "Amount": "5000000",
+            "Amount2": {
+                "currency": "0158415500000000C1F76FF6ECB0BAC600000000",
+                "issuer": "rGvdqXNwMbSwRiubF4PhhVqzhkiaTDPgU",
+                "value": "-0.0000123"
+            },
+            "Asset": { "currency": "XRP" },
+            "Asset2": {
+                "currency": "USD",
+                "issuer": "rGvdqXNwMbSwRiubF4PhhVqzhkiaTDPgU"
+            },
+            "BaseAsset": "XRP",
+            "QuoteAsset": "USD",
+            "MPTokenIssuanceID": "000000010707070707070707070707070707070707070707",
+            "Amendments": [
+                "0707070707070707070707070707070707070707070707070707070707070707",
+                "ABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABAB"
+            ],
+            "Paths": [
+                [
+                    { "account": "raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC" },
+                    { "currency": "USD", "issuer": "rGvdqXNwMbSwRiubF4PhhVqzhkiaTDPgU" }
+                ],
+                [{ "currency": "XRP" }]
+            ],
+            "XChainBridge": {
+                "LockingChainDoor": "raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC",
+                "LockingChainIssue": { "currency": "XRP" },
+                "IssuingChainDoor": "rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm",
+                "IssuingChainIssue": { "currency": "XRP" }
+            },
+            "Memos": [
+                { "Memo": { "MemoType": "74657374", "MemoData": "ABCDEF" } }
+            ]
+        });
+        let encoded = encode(&json).unwrap();
+        assert_eq!(decode(&encoded), Ok(json));
+    }
+
+    #[test]
+    fn invalid_json() {
+        assert!(encode(&json!([])).is_err());
+        assert!(encode(&json!({ "Unknown": 1 })).is_err());
+        assert!(encode(&json!({ "TransactionType": "Unknown" })).is_err());
+        assert!(encode(&json!({ "Sequence": "1" })).is_err());
+        assert!(encode(&json!({ "Sequence": 4294967296_u64 })).is_err());
+        assert!(encode(&json!({ "Fee": "-1" })).is_err());
+        assert!(encode(&json!({ "Fee": "100000000000000001" })).is_err());
+        assert!(encode(&json!({ "Account": "raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFD" })).is_err());
+        assert!(encode(&json!({ "OwnerNode": "0x10" })).is_err());
+        assert!(encode(&json!({ "Memos": [{ "Memo": {}, "Signer": {} }] })).is_err());
+        assert!(encode(&json!({ "Memos": [{ "Account": {} }] })).is_err());
+        assert_eq!(
+            encode(&json!({ "Fee": "1.5" })),
+            Err(Error::InvalidData(
+                "Fee: invalid XRP amount: 1.5".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn nesting_depth() {
+        let nested = |depth: usize| (0..depth).fold(json!({}), |inner, _| json!({ "Memo": inner }));
+        let encoded = encode(&nested(10)).unwrap();
+        assert_eq!(decode(&encoded), Ok(nested(10)));
+        assert!(encode(&nested(11)).is_err());
+        assert!(decode(&[0xea; 1_000_000]).is_err());
+    }
+}
@@ -0,0 +1,157 @@
+//! Derive XRPL key pairs from (16 byte) family seeds as rippled does
+//!
+//! https://xrpl.org/docs/concepts/accounts/cryptographic-keys#key-derivation
+use alloc::string::ToString;
+
+use k256::{
+    elliptic_curve::{sec1::ToEncodedPoint, PrimeField},
+    NonZeroScalar, Scalar, SecretKey,
+};
+
+pub use crate::public_key::KeyType;
+use crate::{public_key::PublicKey, utils::sha512_half, Error};
+
+/// An XRPL account key pair
+#[derive(Clone, PartialEq, Eq)]
+pub struct KeyPair {
+    private_key: [u8; 32],
+    public_key: PublicKey,
+}
+
+impl core::fmt::Debug for KeyPair {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // don't leak the private key
+        f.debug_struct("KeyPair")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KeyPair {
+    /// Derive the `key_type` key pair of `seed`
+    pub fn from_seed(seed: [u8; 16], key_type: KeyType) -> Result<Self, Error> {
+        match key_type {
+            KeyType::Secp256k1 => Self::secp256k1_from_seed(seed),
+            KeyType::Ed25519 => Ok(Self::ed25519_from_seed(seed)),
+        }
+    }
+    /// Derive the secp256k1 key pair of `seed` i.e. the root key pair tweaked by the intermediate key of account 0
+    pub fn secp256k1_from_seed(seed: [u8; 16]) -> Result<Self, Error> {
+        let root_private_key = derive_scalar(&seed, None)?;
+        let root_public_key = secp256k1_public_key(root_private_key);
+        let intermediate_private_key = derive_scalar(&root_public_key, Some(0))?;
+
+        let private_key = NonZeroScalar::new(*root_private_key + *intermediate_private_key)
+            .into_option()
+            .ok_or_else(|| Error::OutOfRange("derived private key".to_string()))?;
+
+        Ok(Self {
+            private_key: private_key.to_repr().into(),
+            public_key: PublicKey::Secp256k1(secp256k1_public_key(private_key).try_into()?),
+        })
+    }
+    /// Derive the ed25519 key pair of `seed` i.e. the private key is the SHA-512Half of `seed`
+    pub fn ed25519_from_seed(seed: [u8; 16]) -> Self {
+        let private_key = sha512_half(&seed);
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&private_key);
+
+        Self {
+            private_key,
+            public_key: PublicKey::Ed25519(signing_key.verifying_key().to_bytes()),
+        }
+    }
+    /// The signing algorithm of this key pair
+    pub fn key_type(&self) -> KeyType {
+        self.public_key.key_type()
+    }
+    /// The private key (secp256k1 scalar or ed25519 secret key)
+    pub fn private_key(&self) -> [u8; 32] {
+        self.private_key
+    }
+    /// The public key
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+    /// The XRPL account ID of this key pair
+    pub fn account_id(&self) -> [u8; 20] {
+        self.public_key.account_id()
+    }
+}
+
+/// Derive a secp256k1 private key from `data` (and `account_index` for intermediate keys)
+///
+/// SHA-512Half(data || [account_index] || sequence) for the first sequence giving a valid scalar
+fn derive_scalar(data: &[u8], account_index: Option<u32>) -> Result<NonZeroScalar, Error> {
+    for sequence in 0_u32..=u32::MAX {
+        let mut buf = data.to_vec();
+        if let Some(account_index) = account_index {
+            buf.extend_from_slice(&account_index.to_be_bytes());
+        }
+        buf.extend_from_slice(&sequence.to_be_bytes());
+
+        let scalar: Option<Scalar> = Scalar::from_repr(sha512_half(&buf).into()).into();
+        if let Some(scalar) = scalar.and_then(|s| NonZeroScalar::new(s).into()) {
+            return Ok(scalar);
+        }
+    }
+    Err(Error::OutOfRange("no valid secp256k1 key".to_string()))
+}
+
+/// The compressed secp256k1 public key of `private_key`
+fn secp256k1_public_key(private_key: NonZeroScalar) -> [u8; 33] {
+    SecretKey::from(private_key)
+        .public_key()
+        .to_encoded_point(true)
+        .as_bytes()
+        .try_into()
+        .expect("it is a compressed point")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    /// Seed of the 'masterpassphrase' genesis account
+    const GENESIS_SEED: [u8; 16] = hex!("DEDCE9CE67B451D852FD4E846FCDE31C");
+
+    #[test]
+    fn secp256k1_from_seed() {
+        let key_pair = KeyPair::from_seed(GENESIS_SEED, KeyType::Secp256k1).unwrap();
+        assert_eq!(key_pair.key_type(), KeyType::Secp256k1);
+        assert_eq!(
+            key_pair.private_key(),
+            hex!("1ACAAEDECE405B2A958212629E16F2EB46B153EEE94CDD350FDEFF52795525B7")
+        );
+        assert_eq!(
+            key_pair.public_key(),
+            PublicKey::try_from(hex!(
+                "0330E7FC9D56BB25D6893BA3F317AE5BCF33B3291BD63DB32654A313222F7FD020"
+            ))
+            .unwrap()
+        );
+        // rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh
+        assert_eq!(
+            key_pair.account_id(),
+            hex!("B5F762798A53D543A014CAF8B297CFF8F2F937E8")
+        );
+    }
+
+    #[test]
+    fn ed25519_from_seed() {
+        let key_pair = KeyPair::from_seed(GENESIS_SEED, KeyType::Ed25519).unwrap();
+        assert_eq!(key_pair.key_type(), KeyType::Ed25519);
+        assert_eq!(
+            key_pair.private_key(),
+            hex!("93D09224D09221B8845E7A9772E0D6259CD01029C557CD95978CC674E0192B25")
+        );
+        assert_eq!(
+            key_pair.public_key().to_bytes(),
+            hex!("EDAAC3F98BB94F451804EF5993C847DAAA4E6154F455635659D88AA5C80F156303")
+        );
+        assert_eq!(
+            key_pair.account_id(),
+            hex!("AA066C988C712815CC37AF71472B7CBBBD4E2A0A")
+        );
+    }
+}
@@ -1,14 +1,31 @@
 #![cfg_attr(not(test), no_std)]
 
 extern crate alloc;
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
 #[cfg(not(test))]
 pub use alloc::vec::Vec;
 #[cfg(test)]
 pub use std::vec::Vec;
 
+pub mod address;
+pub mod definitions;
 mod error;
 pub mod field;
+#[cfg(feature = "json")]
+pub mod json;
+#[cfg(feature = "keys")]
+pub mod keys;
+pub mod multi_sign;
+pub mod public_key;
+#[cfg(feature = "serde")]
+mod serde_impls;
+pub mod signature;
 pub mod traits;
 pub mod transaction;
 pub mod types;
 pub mod utils;
+#[cfg(any(feature = "secp256k1", feature = "ed25519"))]
+pub mod verify;
+
+pub use error::Error;
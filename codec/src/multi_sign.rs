@@ -0,0 +1,213 @@
+//! Collect multi-signatures for a tx until its signer list quorum is met
+use alloc::{collections::BTreeMap, string::ToString};
+
+use crate::{public_key::PublicKey, transaction::SignerListSet, Error, Vec};
+
+/// Collects (public key, signature)s from the members of a signer list as they arrive
+///
+/// The collected signatures are ready to attach to the tx once their combined `SignerWeight` meets the `SignerQuorum`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSignCollector {
+    /// the `SignerQuorum` of the signer list
+    quorum: u32,
+    /// account ID => `SignerWeight` of each signer entry
+    signer_weights: BTreeMap<[u8; 20], u16>,
+    /// account ID => (public key, signature) of signers so far
+    signatures: BTreeMap<[u8; 20], (PublicKey, Vec<u8>)>,
+    /// sum of the weights of signers so far
+    weight: u32,
+}
+
+impl MultiSignCollector {
+    /// Create a collector for the given signer list
+    ///
+    /// - `signer_quorum` signer quorum required
+    /// - `signer_entries` (account, weight)s of the signers which can participate
+    ///
+    /// Fails if the quorum is zero, an account is listed twice or the quorum can never be met
+    pub fn new(signer_quorum: u32, signer_entries: Vec<([u8; 20], u16)>) -> Result<Self, Error> {
+        if signer_quorum == 0 {
+            return Err(Error::InvalidData("zero signer quorum".to_string()));
+        }
+        let mut signer_weights = BTreeMap::new();
+        for (account, weight) in signer_entries {
+            if signer_weights.insert(account, weight).is_some() {
+                return Err(Error::InvalidData("duplicate signer entry".to_string()));
+            }
+        }
+        let total_weight: u32 = signer_weights.values().map(|w| *w as u32).sum();
+        if total_weight < signer_quorum {
+            return Err(Error::OutOfRange(
+                "signer quorum exceeds total signer weight".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            quorum: signer_quorum,
+            signer_weights,
+            signatures: BTreeMap::new(),
+            weight: 0,
+        })
+    }
+    /// Create a collector for the signer list set by `signer_list_set`
+    pub fn from_signer_list_set(signer_list_set: &SignerListSet) -> Result<Self, Error> {
+        Self::new(
+            signer_list_set.signer_quorum(),
+            signer_list_set.signer_entries(),
+        )
+    }
+    /// Add a signer's (public key, signature)
+    ///
+    /// Fails if the signer is not in the signer list or has already signed
+    pub fn add_signature(
+        &mut self,
+        public_key: PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<(), Error> {
+        let account = public_key.account_id();
+        let weight = *self
+            .signer_weights
+            .get(&account)
+            .ok_or_else(|| Error::InvalidData("unknown multi-signer".to_string()))?;
+        if self.signatures.contains_key(&account) {
+            return Err(Error::InvalidData("duplicate multi-signer".to_string()));
+        }
+        self.signatures.insert(account, (public_key, signature));
+        self.weight += weight as u32;
+
+        Ok(())
+    }
+    /// The signer quorum required
+    pub fn quorum(&self) -> u32 {
+        self.quorum
+    }
+    /// Sum of the `SignerWeight`s of signers so far
+    pub fn weight(&self) -> u32 {
+        self.weight
+    }
+    /// Whether the signer weight so far meets the quorum
+    pub fn is_quorum_met(&self) -> bool {
+        self.weight >= self.quorum
+    }
+    /// The (public key, signature)s collected, to attach with e.g. `Payment::attach_multi_signatures`
+    ///
+    /// Fails until the quorum is met
+    pub fn signatures(&self) -> Result<Vec<(PublicKey, Vec<u8>)>, Error> {
+        if !self.is_quorum_met() {
+            return Err(Error::OutOfRange("signer quorum not met".to_string()));
+        }
+        Ok(self.signatures.values().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        traits::BinarySerialize,
+        transaction::{Payment, SignerListSet},
+    };
+
+    fn signer(n: u8) -> (PublicKey, [u8; 20]) {
+        let mut public_key = [n; 33];
+        public_key[0] = 0x02;
+        let public_key = PublicKey::try_from(public_key).unwrap();
+        (public_key, public_key.account_id())
+    }
+
+    #[test]
+    fn collect_until_quorum() {
+        let (pk_1, account_1) = signer(2);
+        let (pk_2, account_2) = signer(3);
+        let (_, account_3) = signer(4);
+        let signer_list_set = SignerListSet::new(
+            [1_u8; 20],
+            1_000,
+            1_u32,
+            1_u32,
+            3_u32,
+            vec![(account_1, 1_u16), (account_2, 2_u16), (account_3, 1_u16)],
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        let mut collector = MultiSignCollector::from_signer_list_set(&signer_list_set).unwrap();
+        assert_eq!(collector.quorum(), 3);
+
+        collector.add_signature(pk_1, vec![1_u8; 71]).unwrap();
+        assert_eq!(collector.weight(), 1);
+        assert!(!collector.is_quorum_met());
+        assert!(collector.signatures().is_err());
+
+        collector.add_signature(pk_2, vec![2_u8; 72]).unwrap();
+        assert_eq!(collector.weight(), 3);
+        assert!(collector.is_quorum_met());
+
+        let signatures = collector.signatures().unwrap();
+        assert_eq!(signatures.len(), 2);
+
+        let mut payment = Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            None,
+        );
+        let mut expected = payment.clone();
+        payment.attach_multi_signatures(signatures).unwrap();
+        expected
+            .attach_multi_signatures(vec![(pk_2, vec![2_u8; 72]), (pk_1, vec![1_u8; 71])])
+            .unwrap();
+        assert_eq!(
+            payment.binary_serialize(false),
+            expected.binary_serialize(false)
+        );
+    }
+
+    #[test]
+    fn reject_unknown_and_duplicate_signers() {
+        let (pk_1, account_1) = signer(2);
+        let (pk_2, _) = signer(3);
+        let mut collector = MultiSignCollector::new(1, vec![(account_1, 1_u16)]).unwrap();
+
+        assert_eq!(
+            collector.add_signature(pk_2, vec![2_u8; 72]),
+            Err(Error::InvalidData("unknown multi-signer".to_string()))
+        );
+        collector.add_signature(pk_1, vec![1_u8; 71]).unwrap();
+        assert_eq!(
+            collector.add_signature(pk_1, vec![1_u8; 71]),
+            Err(Error::InvalidData("duplicate multi-signer".to_string()))
+        );
+        assert_eq!(collector.weight(), 1);
+    }
+
+    #[test]
+    fn ed25519_signer() {
+        let public_key = PublicKey::Ed25519([2_u8; 32]);
+        let (secp256k1_public_key, secp256k1_account) = signer(2);
+        let mut collector = MultiSignCollector::new(
+            2,
+            vec![(public_key.account_id(), 1_u16), (secp256k1_account, 1_u16)],
+        )
+        .unwrap();
+        collector.add_signature(public_key, vec![1_u8; 64]).unwrap();
+        collector
+            .add_signature(secp256k1_public_key, vec![2_u8; 71])
+            .unwrap();
+        assert!(collector.is_quorum_met());
+    }
+
+    #[test]
+    fn invalid_signer_list() {
+        assert!(MultiSignCollector::new(0, vec![([1_u8; 20], 1_u16)]).is_err());
+        assert!(
+            MultiSignCollector::new(3, vec![([1_u8; 20], 1_u16), ([2_u8; 20], 1_u16)]).is_err()
+        );
+        assert!(
+            MultiSignCollector::new(1, vec![([1_u8; 20], 1_u16), ([1_u8; 20], 1_u16)]).is_err()
+        );
+    }
+}
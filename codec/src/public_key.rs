@@ -0,0 +1,180 @@
+//! XRPL account public keys
+use alloc::string::ToString;
+
+use crate::{utils::public_key_to_account_id, Error};
+
+/// Prefix of an ed25519 public key in XRPL
+pub const ED25519_PUBLIC_KEY_PREFIX: u8 = 0xed;
+
+/// Signing algorithm of a key (pair)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyType {
+    Secp256k1,
+    Ed25519,
+}
+
+/// A compressed secp256k1 public key i.e. 33 bytes with a 0x02/0x03 prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Secp256k1PublicKey([u8; 33]);
+
+impl Secp256k1PublicKey {
+    /// The 33 byte serialized public key
+    pub fn as_bytes(&self) -> &[u8; 33] {
+        &self.0
+    }
+}
+
+/// Fails unless the prefix is 0x02 or 0x03
+impl TryFrom<[u8; 33]> for Secp256k1PublicKey {
+    type Error = Error;
+    fn try_from(public_key: [u8; 33]) -> Result<Self, Self::Error> {
+        match public_key[0] {
+            0x02 | 0x03 => Ok(Self(public_key)),
+            _ => Err(Error::InvalidData(
+                "secp256k1 public key must be compressed".to_string(),
+            )),
+        }
+    }
+}
+
+impl From<Secp256k1PublicKey> for [u8; 33] {
+    fn from(public_key: Secp256k1PublicKey) -> Self {
+        public_key.0
+    }
+}
+
+/// An XRPL account public key
+///
+/// Both key types are 33 bytes when serialized e.g. as `SigningPubKey`, ed25519 keys are prefixed with 0xED
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PublicKey {
+    /// A compressed secp256k1 public key
+    Secp256k1(Secp256k1PublicKey),
+    /// An ed25519 public key (without prefix)
+    Ed25519([u8; 32]),
+}
+
+impl PublicKey {
+    /// The 33 byte serialized public key
+    pub fn to_bytes(&self) -> [u8; 33] {
+        match self {
+            Self::Secp256k1(public_key) => public_key.0,
+            Self::Ed25519(public_key) => {
+                let mut buf = [ED25519_PUBLIC_KEY_PREFIX; 33];
+                buf[1..].copy_from_slice(public_key);
+                buf
+            }
+        }
+    }
+    /// The signing algorithm of this public key
+    pub fn key_type(&self) -> KeyType {
+        match self {
+            Self::Secp256k1(_) => KeyType::Secp256k1,
+            Self::Ed25519(_) => KeyType::Ed25519,
+        }
+    }
+    /// The XRPL account ID of this public key
+    pub fn account_id(&self) -> [u8; 20] {
+        public_key_to_account_id(*self)
+    }
+}
+
+/// Parse a serialized public key e.g. from a `SigningPubKey`
+///
+/// Fails unless it is 33 bytes with a 0xED (ed25519) or 0x02/0x03 (compressed secp256k1) prefix
+impl TryFrom<&[u8]> for PublicKey {
+    type Error = Error;
+    fn try_from(public_key: &[u8]) -> Result<Self, Self::Error> {
+        let public_key: [u8; 33] = public_key
+            .try_into()
+            .map_err(|_| Error::InvalidData("public key must be 33 bytes".to_string()))?;
+        Self::try_from(public_key)
+    }
+}
+
+/// The key type is given by the prefix, see `TryFrom<&[u8]>`
+impl TryFrom<[u8; 33]> for PublicKey {
+    type Error = Error;
+    fn try_from(public_key: [u8; 33]) -> Result<Self, Self::Error> {
+        match public_key[0] {
+            ED25519_PUBLIC_KEY_PREFIX => Ok(Self::Ed25519(
+                public_key[1..].try_into().expect("it is 32 bytes"),
+            )),
+            0x02 | 0x03 => Ok(Self::Secp256k1(Secp256k1PublicKey(public_key))),
+            _ => Err(Error::InvalidData("unknown public key type".to_string())),
+        }
+    }
+}
+
+impl From<PublicKey> for [u8; 33] {
+    fn from(public_key: PublicKey) -> Self {
+        public_key.to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{field::SigningPubKey, types::BlobType, utils::secp256k1_public_key_to_account_id};
+
+    #[test]
+    fn public_key_types() {
+        let mut ed25519 = [7_u8; 33];
+        ed25519[0] = ED25519_PUBLIC_KEY_PREFIX;
+        assert_eq!(
+            PublicKey::try_from(ed25519),
+            Ok(PublicKey::Ed25519([7_u8; 32]))
+        );
+        assert_eq!(PublicKey::Ed25519([7_u8; 32]).to_bytes(), ed25519);
+
+        let mut secp256k1 = [7_u8; 33];
+        secp256k1[0] = 0x02;
+        let public_key = PublicKey::try_from(secp256k1.as_slice()).unwrap();
+        assert_eq!(
+            public_key,
+            PublicKey::Secp256k1(Secp256k1PublicKey::try_from(secp256k1).unwrap())
+        );
+        assert_eq!(
+            public_key.account_id(),
+            secp256k1_public_key_to_account_id(secp256k1)
+        );
+
+        assert!(PublicKey::try_from([7_u8; 33].as_slice()).is_err());
+        assert!(PublicKey::try_from([7_u8; 33]).is_err());
+        assert!(Secp256k1PublicKey::try_from([7_u8; 33]).is_err());
+        assert!(Secp256k1PublicKey::try_from(ed25519).is_err());
+        assert!(PublicKey::try_from(&ed25519[1..]).is_err());
+    }
+
+    #[test]
+    fn signing_pub_key() {
+        // an invalid secp256k1 key never becomes a `SigningPubKey`
+        assert_eq!(
+            Secp256k1PublicKey::try_from([0x07; 33])
+                .map(|public_key| SigningPubKey::from(PublicKey::Secp256k1(public_key))),
+            Err(Error::InvalidData(
+                "secp256k1 public key must be compressed".to_string()
+            ))
+        );
+        assert!(PublicKey::try_from([0x07; 33])
+            .map(SigningPubKey::from)
+            .is_err());
+        assert_eq!(
+            PublicKey::try_from([0x03; 33]).map(SigningPubKey::from),
+            Ok(SigningPubKey(BlobType(vec![0x03; 33])))
+        );
+    }
+
+    #[test]
+    fn ed25519_account_id() {
+        let public_key = PublicKey::try_from(hex_literal::hex!(
+            "EDDF4ECB8F34A168143B928D48EFE625501FB8552403BBBD3FC038A5788951D770"
+        ))
+        .unwrap();
+        assert!(matches!(public_key, PublicKey::Ed25519(_)));
+        assert_eq!(
+            hex::encode(public_key.account_id()),
+            "d96cb910955ab40a0e987eee82bb3cedd4441aaa"
+        );
+    }
+}
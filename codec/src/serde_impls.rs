@@ -0,0 +1,1090 @@
+//! serde impls matching the rippled JSON representation of codec types
+//!
+//! XRP amounts are drop strings, token amounts are `{currency, issuer, value}` objects, account IDs are classic
+//! addresses and binary values are uppercase hex. Objects are keyed by their 'definitions.json' field names
+use alloc::{format, string::String};
+use core::fmt;
+
+use serde::{
+    de::{self, value::MapAccessDeserializer, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    ser::{self, SerializeMap},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    address::{decode_classic_address, encode_classic_address},
+    definitions::{field_by_id, field_by_name, FieldDefinition},
+    field::{Account, FieldId, SignerWeight, SigningPubKey, TransactionTypeCode, TxnSignature},
+    traits::{BinaryDeserialize, BinarySerialize},
+    transaction::{
+        NFTokenAcceptOffer, NFTokenCreateOffer, Payment, PaymentAltCurrency,
+        PaymentAltCurrencyWithDestinationTag, PaymentWithDestinationTag, SignerListSet,
+        XAddressPayment,
+    },
+    types::*,
+    Error, Vec,
+};
+
+/// UInt64 fields which are given in base 10 rather than hex
+const BASE_10_UINT64_FIELDS: &[&str] = &[
+    "MaximumAmount",
+    "OutstandingAmount",
+    "MPTAmount",
+    "LockedAmount",
+];
+/// Smallest normalized (16 digit) mantissa of numbers
+const MANTISSA_MIN: u64 = 1_000_000_000_000_000;
+/// Symbols allowed in a standard currency code besides letters and digits
+const CURRENCY_CODE_SYMBOLS: &[u8] = b"?!@#$%^&*<>(){}[]|";
+
+/// Integer types are JSON numbers
+macro_rules! serde_integer {
+    ($($name:ident($inner:ty)),*) => {
+        $(
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.0.serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    <$inner>::deserialize(deserializer).map(Self)
+                }
+            }
+        )*
+    };
+}
+
+/// Fixed width binary types are uppercase hex strings
+macro_rules! serde_fixed_bytes {
+    ($($name:ident),*) => {
+        $(
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_hex(&self.0, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_fixed_hex(deserializer).map(Self)
+                }
+            }
+        )*
+    };
+}
+
+serde_integer!(UInt8Type(u8), UInt16Type(u16), UInt32Type(u32));
+serde_fixed_bytes!(
+    Hash128Type,
+    Hash160Type,
+    Hash256Type,
+    UInt96Type,
+    UInt192Type,
+    UInt384Type,
+    UInt512Type
+);
+
+/// A hex string (as the 64-bit value may not fit a JSON number)
+impl Serialize for UInt64Type {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("{:016X}", self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for UInt64Type {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        parse_uint64(&String::deserialize(deserializer)?, 16)
+            .map(Self)
+            .map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for AccountIdType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode_classic_address(self))
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountIdType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        decode_classic_address(&String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for BlobType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_hex(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BlobType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_hex(deserializer).map(Self)
+    }
+}
+
+impl Serialize for Vector256Type {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|hash| Hash256Type(*hash)))
+    }
+}
+
+impl<'de> Deserialize<'de> for Vector256Type {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hashes = Vec::<Hash256Type>::deserialize(deserializer)?;
+        Ok(Self(hashes.into_iter().map(|hash| hash.0).collect()))
+    }
+}
+
+/// The 3 character code of standard currencies, otherwise the 160-bit hex code
+impl Serialize for CurrencyCodeType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            CurrencyCodeType::Standard(code) if is_standard_currency_code(code) => {
+                serializer.serialize_str(core::str::from_utf8(code).expect("it is ascii"))
+            }
+            _ => serialize_hex(
+                &self.binary_serialize(false).map_err(ser::Error::custom)?,
+                serializer,
+            ),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CurrencyCodeType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        match code.len() {
+            3 if is_standard_currency_code(code.as_bytes()) => Ok(CurrencyCodeType::Standard(
+                code.as_bytes().try_into().expect("it is 3 bytes"),
+            )),
+            40 => {
+                let code = decode_hex(&code).map_err(de::Error::custom)?;
+                CurrencyCodeType::binary_deserialize(&code).map_err(de::Error::custom)
+            }
+            _ => Err(de::Error::custom(format!(
+                "invalid currency code: {}",
+                code
+            ))),
+        }
+    }
+}
+
+/// "XRP" or the currency code
+impl Serialize for CurrencyType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            CurrencyType::Xrp => serializer.serialize_str("XRP"),
+            CurrencyType::Issued(currency) => currency.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CurrencyType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        if code == "XRP" {
+            return Ok(CurrencyType::Xrp);
+        }
+        CurrencyCodeType::deserialize(de::value::StringDeserializer::<D::Error>::new(code))
+            .map(CurrencyType::Issued)
+    }
+}
+
+/// An issue as JSON i.e. the issuer is only present for issued currencies
+#[derive(Serialize, Deserialize)]
+struct IssueJson {
+    currency: CurrencyType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    issuer: Option<AccountIdType>,
+}
+
+impl Serialize for IssueType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let issue = match self {
+            IssueType::Xrp => IssueJson {
+                currency: CurrencyType::Xrp,
+                issuer: None,
+            },
+            IssueType::Issued(currency, issuer) => IssueJson {
+                currency: CurrencyType::Issued(currency.clone()),
+                issuer: Some(issuer.clone()),
+            },
+        };
+        issue.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssueType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match IssueJson::deserialize(deserializer)? {
+            IssueJson {
+                currency: CurrencyType::Xrp,
+                issuer: None,
+            } => Ok(IssueType::Xrp),
+            IssueJson {
+                currency: CurrencyType::Issued(currency),
+                issuer: Some(issuer),
+            } => Ok(IssueType::Issued(currency, issuer)),
+            _ => Err(de::Error::custom("only issued currencies have an issuer")),
+        }
+    }
+}
+
+/// A decimal string e.g. "3.14"
+impl Serialize for IssuedValueType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuedValueType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// A token amount as JSON
+#[derive(Serialize, Deserialize)]
+struct IssuedAmountJson {
+    currency: CurrencyCodeType,
+    issuer: AccountIdType,
+    value: IssuedValueType,
+}
+
+impl Serialize for IssuedAmountType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        IssuedAmountJson {
+            currency: self.currency().clone(),
+            issuer: self.issuer().clone(),
+            value: self.value().clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssuedAmountType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let IssuedAmountJson {
+            currency,
+            issuer,
+            value,
+        } = IssuedAmountJson::deserialize(deserializer)?;
+        IssuedAmountType::from_issued_value(value, currency, issuer).map_err(de::Error::custom)
+    }
+}
+
+/// XRP amounts are drop strings e.g. "1000", token amounts are `{currency, issuer, value}`
+impl Serialize for AmountType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            AmountType::Drops(drops) => serializer.collect_str(drops),
+            AmountType::Issued(amount) => amount.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AmountType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AmountVisitor;
+
+        impl<'de> Visitor<'de> for AmountVisitor {
+            type Value = AmountType;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a drops string or token amount object")
+            }
+
+            fn visit_str<E: de::Error>(self, drops: &str) -> Result<Self::Value, E> {
+                if drops.is_empty() || !drops.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(E::custom(format!("invalid XRP amount: {}", drops)));
+                }
+                match drops.parse::<u64>() {
+                    Ok(drops) if drops <= DROPS_MAX => Ok(AmountType::Drops(drops)),
+                    _ => Err(E::custom(format!(
+                        "XRP amount exceeds max. supply: {}",
+                        drops
+                    ))),
+                }
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                IssuedAmountType::deserialize(MapAccessDeserializer::new(map))
+                    .map(AmountType::Issued)
+            }
+        }
+
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+/// A path step as JSON i.e. only the present members
+#[derive(Serialize, Deserialize)]
+struct PathStepJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    account: Option<AccountIdType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    currency: Option<CurrencyType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    issuer: Option<AccountIdType>,
+}
+
+impl Serialize for PathStepType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        PathStepJson {
+            account: self.account.clone(),
+            currency: self.currency.clone(),
+            issuer: self.issuer.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PathStepType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let PathStepJson {
+            account,
+            currency,
+            issuer,
+        } = PathStepJson::deserialize(deserializer)?;
+        Ok(Self {
+            account,
+            currency,
+            issuer,
+        })
+    }
+}
+
+impl Serialize for PathSetType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PathSetType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<Vec<PathStepType>>::deserialize(deserializer).map(Self)
+    }
+}
+
+/// A bridge as JSON, keyed as rippled
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct XChainBridgeJson {
+    locking_chain_door: AccountIdType,
+    locking_chain_issue: IssueType,
+    issuing_chain_door: AccountIdType,
+    issuing_chain_issue: IssueType,
+}
+
+impl Serialize for XChainBridgeType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        XChainBridgeJson {
+            locking_chain_door: self.locking_chain_door.clone(),
+            locking_chain_issue: self.locking_chain_issue.clone(),
+            issuing_chain_door: self.issuing_chain_door.clone(),
+            issuing_chain_issue: self.issuing_chain_issue.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for XChainBridgeType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bridge = XChainBridgeJson::deserialize(deserializer)?;
+        Ok(Self {
+            locking_chain_door: bridge.locking_chain_door,
+            locking_chain_issue: bridge.locking_chain_issue,
+            issuing_chain_door: bridge.issuing_chain_door,
+            issuing_chain_issue: bridge.issuing_chain_issue,
+        })
+    }
+}
+
+/// As rippled i.e. scientific notation unless the exponent is within -25..=-5
+impl Serialize for NumberType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.mantissa == 0 {
+            return serializer.serialize_str("0");
+        }
+        if self.exponent != 0 && !(-25..=-5).contains(&self.exponent) {
+            return serializer.collect_str(&format_args!("{}e{}", self.mantissa, self.exponent));
+        }
+        serializer.serialize_str(&decimal_string(
+            self.mantissa < 0,
+            self.mantissa.unsigned_abs(),
+            self.exponent,
+        ))
+    }
+}
+
+/// A decimal string normalized as rippled
+impl<'de> Deserialize<'de> for NumberType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let (negative, mut mantissa, mut exponent) =
+            parse_decimal(&value).map_err(de::Error::custom)?;
+        if mantissa == 0 {
+            // rippled's zero has the lowest exponent
+            return Ok(Self {
+                mantissa: 0,
+                exponent: i32::MIN,
+            });
+        }
+        while mantissa < MANTISSA_MIN {
+            mantissa *= 10;
+            exponent = exponent
+                .checked_sub(1)
+                .ok_or_else(|| de::Error::custom(format!("number out of range: {}", value)))?;
+        }
+
+        Ok(Self {
+            mantissa: if negative {
+                -(mantissa as i64)
+            } else {
+                mantissa as i64
+            },
+            exponent,
+        })
+    }
+}
+
+/// A signer entry as JSON i.e. the inner object of a `SignerEntry` array item
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SignerEntryFields {
+    account: Account,
+    signer_weight: SignerWeight,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignerEntryJson {
+    #[serde(rename = "SignerEntry")]
+    signer_entry: SignerEntryFields,
+}
+
+/// As a `SignerEntries` item i.e. `{"SignerEntry": {"Account": .., "SignerWeight": ..}}`
+impl Serialize for SignerEntryType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SignerEntryJson {
+            signer_entry: SignerEntryFields {
+                account: self.0.clone(),
+                signer_weight: self.1.clone(),
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignerEntryType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SignerEntryJson {
+            signer_entry:
+                SignerEntryFields {
+                    account,
+                    signer_weight,
+                },
+        } = SignerEntryJson::deserialize(deserializer)?;
+        Ok(Self(account, signer_weight))
+    }
+}
+
+/// A multi-signature as JSON i.e. the inner object of a `Signer` array item
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct SignerFields {
+    account: Account,
+    signing_pub_key: SigningPubKey,
+    txn_signature: TxnSignature,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignerJson {
+    #[serde(rename = "Signer")]
+    signer: SignerFields,
+}
+
+/// As a `Signers` item i.e. `{"Signer": {"Account": .., "SigningPubKey": .., "TxnSignature": ..}}`
+impl Serialize for SignerType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SignerJson {
+            signer: SignerFields {
+                account: self.0.clone(),
+                signing_pub_key: self.1.clone(),
+                txn_signature: self.2.clone(),
+            },
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignerType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let SignerJson {
+            signer:
+                SignerFields {
+                    account,
+                    signing_pub_key,
+                    txn_signature,
+                },
+        } = SignerJson::deserialize(deserializer)?;
+        Ok(Self(account, signing_pub_key, txn_signature))
+    }
+}
+
+impl<T: Serialize> Serialize for STArrayType<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for STArrayType<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Serialize for STObjectType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for STObjectType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        STObject::deserialize(deserializer).map(Self)
+    }
+}
+
+/// An array item keyed by its inner object field e.g. `{"Memo": {..}}`
+impl Serialize for STArrayItemType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(field_name(self.0).map_err(ser::Error::custom)?, &self.1)?;
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for STArrayItemType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ItemSeed(0).deserialize(deserializer)
+    }
+}
+
+/// Deserializes an array item whose object is at the given nesting depth
+struct ItemSeed(usize);
+
+impl<'de> DeserializeSeed<'de> for ItemSeed {
+    type Value = STArrayItemType;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        struct ItemVisitor(usize);
+
+        impl<'de> Visitor<'de> for ItemVisitor {
+            type Value = STArrayItemType;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an object with a single inner object field")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let name = map
+                    .next_key::<String>()?
+                    .ok_or_else(|| de::Error::custom("empty STArray item"))?;
+                let field = field_by_name(&name)
+                    .filter(|f| f.is_serialized && f.type_code as u16 == ST_OBJECT_TYPE_CODE)
+                    .ok_or_else(|| {
+                        de::Error::custom(format!("STArray item {} is not an STObject", name))
+                    })?;
+                let object = map.next_value_seed(ObjectSeed(self.0))?;
+                if map.next_key::<String>()?.is_some() {
+                    return Err(de::Error::custom(
+                        "STArray item must have exactly one field",
+                    ));
+                }
+                Ok(STArrayItemType(field.field_id(), STObjectType(object)))
+            }
+        }
+
+        deserializer.deserialize_map(ItemVisitor(self.0))
+    }
+}
+
+/// Deserializes the items of an array at the given nesting depth
+struct ArraySeed(usize);
+
+impl<'de> DeserializeSeed<'de> for ArraySeed {
+    type Value = Vec<(FieldId, STObject)>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        struct ArrayVisitor(usize);
+
+        impl<'de> Visitor<'de> for ArrayVisitor {
+            type Value = Vec<(FieldId, STObject)>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an array of inner objects")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                check_nesting_depth(self.0).map_err(de::Error::custom)?;
+                let mut items = Vec::new();
+                while let Some(STArrayItemType(field_id, object)) =
+                    seq.next_element_seed(ItemSeed(self.0 + 1))?
+                {
+                    items.push((field_id, object.0));
+                }
+                Ok(items)
+            }
+        }
+
+        deserializer.deserialize_seq(ArrayVisitor(self.0))
+    }
+}
+
+/// Serialized as the inner value i.e. without field specific representations e.g. `TransactionType` names
+impl Serialize for SerializedValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::UInt8(value) => value.serialize(serializer),
+            Self::UInt16(value) => value.serialize(serializer),
+            Self::UInt32(value) => value.serialize(serializer),
+            Self::UInt64(value) => value.serialize(serializer),
+            Self::UInt96(value) => value.serialize(serializer),
+            Self::UInt192(value) => value.serialize(serializer),
+            Self::UInt384(value) => value.serialize(serializer),
+            Self::UInt512(value) => value.serialize(serializer),
+            Self::Hash128(value) => value.serialize(serializer),
+            Self::Hash160(value) => value.serialize(serializer),
+            Self::Hash256(value) => value.serialize(serializer),
+            Self::Amount(value) => value.serialize(serializer),
+            Self::Blob(value) => value.serialize(serializer),
+            Self::AccountId(value) => value.serialize(serializer),
+            Self::Number(value) => value.serialize(serializer),
+            Self::PathSet(value) => value.serialize(serializer),
+            Self::Vector256(value) => value.serialize(serializer),
+            Self::Issue(value) => value.serialize(serializer),
+            Self::XChainBridge(value) => value.serialize(serializer),
+            Self::Currency(value) => value.serialize(serializer),
+            Self::STObject(object) => object.serialize(serializer),
+            Self::STArray(items) => {
+                serializer.collect_seq(items.iter().map(|(field_id, object)| {
+                    STArrayItemType(*field_id, STObjectType(object.clone()))
+                }))
+            }
+        }
+    }
+}
+
+/// A field value with the representation of its field e.g. `TransactionType` names
+struct FieldValue<'a>(&'static FieldDefinition, &'a SerializedValue);
+
+impl Serialize for FieldValue<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let FieldValue(field, value) = self;
+        match value {
+            SerializedValue::UInt16(value) if field.name == "TransactionType" => {
+                let transaction_type =
+                    TransactionTypeCode::from_code(value.0).ok_or_else(|| {
+                        ser::Error::custom(format!("unknown transaction type {}", value.0))
+                    })?;
+                serializer.serialize_str(transaction_type.name())
+            }
+            SerializedValue::UInt64(value) if BASE_10_UINT64_FIELDS.contains(&field.name) => {
+                serializer.collect_str(&value.0)
+            }
+            _ => value.serialize(serializer),
+        }
+    }
+}
+
+/// Deserializes the value of a field by its type, contained by an object at the given nesting depth
+struct FieldSeed(&'static FieldDefinition, usize);
+
+impl<'de> DeserializeSeed<'de> for FieldSeed {
+    type Value = SerializedValue;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let field = self.0;
+        let value = match field.type_code as u16 {
+            UINT8_TYPE_CODE => SerializedValue::UInt8(Deserialize::deserialize(deserializer)?),
+            UINT16_TYPE_CODE if field.name == "TransactionType" => {
+                let name = String::deserialize(deserializer)?;
+                let transaction_type = TransactionTypeCode::from_name(&name).ok_or_else(|| {
+                    de::Error::custom(format!("unknown transaction type {}", name))
+                })?;
+                SerializedValue::UInt16(UInt16Type(transaction_type.code()))
+            }
+            UINT16_TYPE_CODE => SerializedValue::UInt16(Deserialize::deserialize(deserializer)?),
+            UINT32_TYPE_CODE => SerializedValue::UInt32(Deserialize::deserialize(deserializer)?),
+            UINT64_TYPE_CODE if BASE_10_UINT64_FIELDS.contains(&field.name) => {
+                let value = parse_uint64(&String::deserialize(deserializer)?, 10)
+                    .map_err(de::Error::custom)?;
+                SerializedValue::UInt64(UInt64Type(value))
+            }
+            UINT64_TYPE_CODE => SerializedValue::UInt64(Deserialize::deserialize(deserializer)?),
+            UINT96_TYPE_CODE => SerializedValue::UInt96(Deserialize::deserialize(deserializer)?),
+            UINT192_TYPE_CODE => SerializedValue::UInt192(Deserialize::deserialize(deserializer)?),
+            UINT384_TYPE_CODE => SerializedValue::UInt384(Deserialize::deserialize(deserializer)?),
+            UINT512_TYPE_CODE => SerializedValue::UInt512(Deserialize::deserialize(deserializer)?),
+            HASH128_TYPE_CODE => SerializedValue::Hash128(Deserialize::deserialize(deserializer)?),
+            HASH160_TYPE_CODE => SerializedValue::Hash160(Deserialize::deserialize(deserializer)?),
+            HASH256_TYPE_CODE => SerializedValue::Hash256(Deserialize::deserialize(deserializer)?),
+            AMOUNT_TYPE_CODE => SerializedValue::Amount(Deserialize::deserialize(deserializer)?),
+            BLOB_TYPE_CODE => SerializedValue::Blob(Deserialize::deserialize(deserializer)?),
+            ACCOUNT_ID_TYPE_CODE => {
+                SerializedValue::AccountId(Deserialize::deserialize(deserializer)?)
+            }
+            NUMBER_TYPE_CODE => SerializedValue::Number(Deserialize::deserialize(deserializer)?),
+            PATH_SET_TYPE_CODE => SerializedValue::PathSet(Deserialize::deserialize(deserializer)?),
+            VECTOR256_TYPE_CODE => {
+                SerializedValue::Vector256(Deserialize::deserialize(deserializer)?)
+            }
+            ISSUE_TYPE_CODE => SerializedValue::Issue(Deserialize::deserialize(deserializer)?),
+            XCHAIN_BRIDGE_TYPE_CODE => {
+                SerializedValue::XChainBridge(Deserialize::deserialize(deserializer)?)
+            }
+            CURRENCY_TYPE_CODE => {
+                SerializedValue::Currency(Deserialize::deserialize(deserializer)?)
+            }
+            ST_OBJECT_TYPE_CODE => {
+                SerializedValue::STObject(ObjectSeed(self.1 + 1).deserialize(deserializer)?)
+            }
+            ST_ARRAY_TYPE_CODE => {
+                SerializedValue::STArray(ArraySeed(self.1 + 1).deserialize(deserializer)?)
+            }
+            _ => {
+                return Err(de::Error::custom(format!(
+                    "unsupported type {}",
+                    field.type_name
+                )))
+            }
+        };
+        Ok(value)
+    }
+}
+
+/// A map of field names to values in canonical order
+impl Serialize for STObject {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+        for (field_id, value) in self.iter() {
+            let field = field_by_id(*field_id)
+                .ok_or_else(|| ser::Error::custom(format!("unknown field {:?}", field_id)))?;
+            map.serialize_entry(field.name, &FieldValue(field, value))?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for STObject {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        ObjectSeed(0).deserialize(deserializer)
+    }
+}
+
+/// Deserializes an object at the given nesting depth
+struct ObjectSeed(usize);
+
+impl<'de> DeserializeSeed<'de> for ObjectSeed {
+    type Value = STObject;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        struct ObjectVisitor(usize);
+
+        impl<'de> Visitor<'de> for ObjectVisitor {
+            type Value = STObject;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an object of XRPL fields")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                check_nesting_depth(self.0).map_err(de::Error::custom)?;
+                let mut object = STObject::new();
+                while let Some(name) = map.next_key::<String>()? {
+                    let field = field_by_name(&name)
+                        .filter(|f| f.is_serialized)
+                        .ok_or_else(|| de::Error::custom(format!("unknown field {}", name)))?;
+                    let value = map
+                        .next_value_seed(FieldSeed(field, self.0))
+                        .map_err(|err| de::Error::custom(format!("{}: {}", name, err)))?;
+                    if object
+                        .insert(field.field_id(), value)
+                        .map_err(de::Error::custom)?
+                        .is_some()
+                    {
+                        return Err(de::Error::custom(format!("duplicate field {}", name)));
+                    }
+                }
+                Ok(object)
+            }
+        }
+
+        deserializer.deserialize_map(ObjectVisitor(self.0))
+    }
+}
+
+/// Transactions are their (unsigned or signed) object
+macro_rules! serde_transaction {
+    ($($name:ident),*) => {
+        $(
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    transaction_to_object(self)
+                        .map_err(ser::Error::custom)?
+                        .serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let binary = STObject::deserialize(deserializer)?
+                        .binary_serialize(false)
+                        .map_err(de::Error::custom)?;
+                    Self::binary_deserialize(&binary).map_err(de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+serde_transaction!(
+    Payment,
+    PaymentWithDestinationTag,
+    PaymentAltCurrency,
+    PaymentAltCurrencyWithDestinationTag,
+    SignerListSet,
+    NFTokenCreateOffer,
+    NFTokenAcceptOffer
+);
+
+impl Serialize for XAddressPayment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Payment(tx) => tx.serialize(serializer),
+            Self::PaymentWithDestinationTag(tx) => tx.serialize(serializer),
+        }
+    }
+}
+
+/// A `PaymentWithDestinationTag` if there is a `DestinationTag`, otherwise a `Payment`
+impl<'de> Deserialize<'de> for XAddressPayment {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let object = STObject::deserialize(deserializer)?;
+        let has_destination_tag = field_by_name("DestinationTag")
+            .is_some_and(|field| object.get(&field.field_id()).is_some());
+        let binary = object.binary_serialize(false).map_err(de::Error::custom)?;
+        if has_destination_tag {
+            PaymentWithDestinationTag::binary_deserialize(&binary)
+                .map(Self::PaymentWithDestinationTag)
+                .map_err(de::Error::custom)
+        } else {
+            Payment::binary_deserialize(&binary)
+                .map(Self::Payment)
+                .map_err(de::Error::custom)
+        }
+    }
+}
+
+fn transaction_to_object<T: BinarySerialize>(transaction: &T) -> Result<STObject, Error> {
+    STObject::binary_deserialize(&transaction.binary_serialize(false)?)
+}
+
+fn field_name(field_id: FieldId) -> Result<&'static str, Error> {
+    field_by_id(field_id)
+        .map(|field| field.name)
+        .ok_or_else(|| Error::InvalidData(format!("unknown field {:?}", field_id)))
+}
+
+fn serialize_hex<S: Serializer>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex::encode_upper(data))
+}
+
+fn deserialize_hex<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+    decode_hex(&String::deserialize(deserializer)?).map_err(de::Error::custom)
+}
+
+fn deserialize_fixed_hex<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    deserialize_hex(deserializer)?
+        .try_into()
+        .map_err(|_| de::Error::custom(format!("expected {} bytes of hex", N)))
+}
+
+fn decode_hex(data: &str) -> Result<Vec<u8>, Error> {
+    hex::decode(data).map_err(|_| Error::InvalidData(format!("invalid hex: {}", data)))
+}
+
+fn parse_uint64(value: &str, radix: u32) -> Result<u64, Error> {
+    if value.is_empty() || !value.chars().all(|c| c.is_digit(radix)) {
+        return Err(Error::InvalidData(format!("invalid UInt64: {}", value)));
+    }
+    u64::from_str_radix(value, radix).map_err(|_| Error::OutOfRange(format!("UInt64: {}", value)))
+}
+
+/// Standard currency codes are 3 letters, digits or symbols
+fn is_standard_currency_code(code: &[u8]) -> bool {
+    code.iter()
+        .all(|b| b.is_ascii_alphanumeric() || CURRENCY_CODE_SYMBOLS.contains(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{Amount, Fee, Memos, SignerEntry};
+    use serde_json::json;
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_Payment_serde() {
+        let payment = Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            None,
+        );
+        let json = serde_json::to_value(&payment).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "TransactionType": "Payment",
+                "Flags": 2147483648_u32,
+                "SourceTag": 38887387,
+                "Sequence": 1,
+                "TicketSequence": 1,
+                "Amount": "5000000",
+                "Fee": "1000",
+                "SigningPubKey": "",
+                "Account": "raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC",
+                "Destination": "rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<Payment>(json.clone()).unwrap(),
+            payment
+        );
+        assert_eq!(
+            serde_json::from_value::<XAddressPayment>(json).unwrap(),
+            XAddressPayment::Payment(payment)
+        );
+
+        // the transaction type must match
+        let mut json = serde_json::to_value(payment_alt_currency()).unwrap();
+        json["TransactionType"] = "SignerListSet".into();
+        assert!(serde_json::from_value::<PaymentAltCurrency>(json).is_err());
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_PaymentAltCurrency_serde() {
+        let payment = payment_alt_currency();
+        let json = serde_json::to_value(&payment).unwrap();
+        assert_eq!(
+            json["Amount"],
+            json!({
+                "currency": "AST",
+                "issuer": "rGvdqXNwMbSwRiubF4PhhVqzhkiaTDPgU",
+                "value": "3.14"
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<PaymentAltCurrency>(json).unwrap(),
+            payment
+        );
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_SignerListSet_serde() {
+        let signer_list_set = SignerListSet::new(
+            [1_u8; 20],
+            1_000,
+            1_u32,
+            1_u32,
+            2_u32,
+            vec![([2_u8; 20], 1_u16), ([3_u8; 20], 1_u16)],
+            38_887_387_u32,
+            None,
+        );
+        let json = serde_json::to_value(&signer_list_set).unwrap();
+        assert_eq!(json["SignerQuorum"], 2);
+        assert_eq!(
+            json["SignerEntries"][0],
+            json!({
+                "SignerEntry": {
+                    "Account": "rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm",
+                    "SignerWeight": 1
+                }
+            })
+        );
+        assert_eq!(
+            serde_json::from_value::<SignerListSet>(json).unwrap(),
+            signer_list_set
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let fee = Fee(AmountType::Drops(12));
+        assert_eq!(serde_json::to_value(&fee).unwrap(), json!("12"));
+        assert_eq!(serde_json::from_value::<Fee>(json!("12")).unwrap(), fee);
+
+        let entry = SignerEntry(SignerEntryType(
+            Account(AccountIdType([3_u8; 20])),
+            SignerWeight(UInt16Type(2)),
+        ));
+        let json = json!({
+            "SignerEntry": { "Account": "rGvdqXNwMbSwRiubF4PhhVqzhkiaTDPgU", "SignerWeight": 2 }
+        });
+        assert_eq!(serde_json::to_value(&entry).unwrap(), json);
+        assert_eq!(serde_json::from_value::<SignerEntry>(json).unwrap(), entry);
+
+        let mut memo = STObject::new();
+        memo.insert(
+            field_by_name("MemoData").unwrap().field_id(),
+            SerializedValue::Blob(BlobType(vec![0xAB, 0xCD])),
+        )
+        .unwrap();
+        let memos = Memos(STArrayType(vec![STArrayItemType(
+            field_by_name("Memo").unwrap().field_id(),
+            STObjectType(memo),
+        )]));
+        let json = json!([{ "Memo": { "MemoData": "ABCD" } }]);
+        assert_eq!(serde_json::to_value(&memos).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Memos>(json).unwrap(), memos);
+
+        assert!(serde_json::from_value::<Amount>(json!("1.5")).is_err());
+        assert!(
+            serde_json::from_value::<Account>(json!("rGvdqXNwMbSwRiubF4PhhVqzhkiaTDPgV")).is_err()
+        );
+    }
+
+    fn payment_alt_currency() -> PaymentAltCurrency {
+        let amount = IssuedAmountType::from_issued_value(
+            IssuedValueType::from_mantissa_exponent(314, -2).unwrap(),
+            CurrencyCodeType::Standard(*b"AST"),
+            AccountIdType([3_u8; 20]),
+        )
+        .unwrap();
+        PaymentAltCurrency::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            Amount(AmountType::Issued(amount)),
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            None,
+        )
+    }
+}
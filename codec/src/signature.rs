@@ -0,0 +1,214 @@
+//! Signatures as expected in `TxnSignature` i.e. DER encoded secp256k1 or ed25519
+use alloc::string::ToString;
+
+use crate::{public_key::KeyType, Error, Vec};
+
+/// Order of the secp256k1 curve (n)
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+/// Half the order of the secp256k1 curve (n / 2), the largest canonical 'S' value
+const SECP256K1_HALF_ORDER: [u8; 32] = [
+    0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b, 0x20, 0xa0,
+];
+/// DER tag of a sequence
+const DER_SEQUENCE: u8 = 0x30;
+/// DER tag of an integer
+const DER_INTEGER: u8 = 0x02;
+
+/// A fully canonical (low-S), DER encoded secp256k1 signature
+///
+/// https://xrpl.org/docs/concepts/transactions/finality-of-results/canonical-signatures
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerSignature(Vec<u8>);
+
+impl DerSignature {
+    /// Encode the signature (`r`, `s`)
+    ///
+    /// Fails if `r` or `s` are out of range or `s` is not canonical i.e. greater than n / 2
+    pub fn from_rs(r: [u8; 32], s: [u8; 32]) -> Result<Self, Error> {
+        if r == [0_u8; 32] || r >= SECP256K1_ORDER {
+            return Err(Error::OutOfRange("signature 'r' value".to_string()));
+        }
+        if s == [0_u8; 32] {
+            return Err(Error::OutOfRange("signature 's' value".to_string()));
+        }
+        if s > SECP256K1_HALF_ORDER {
+            return Err(Error::InvalidData(
+                "non-canonical signature (high 's' value)".to_string(),
+            ));
+        }
+
+        let r = der_integer(&r);
+        let s = der_integer(&s);
+        let mut buf = Vec::with_capacity(2 + r.len() + s.len());
+        buf.push(DER_SEQUENCE);
+        buf.push((r.len() + s.len()) as u8);
+        buf.extend_from_slice(&r);
+        buf.extend_from_slice(&s);
+
+        Ok(Self(buf))
+    }
+    /// Encode a compact signature i.e. (r || s) with an optional trailing recovery ID (v)
+    pub fn from_compact(signature: &[u8]) -> Result<Self, Error> {
+        if !matches!(signature.len(), 64 | 65) {
+            return Err(Error::InvalidData(
+                "compact signature must be 64 or 65 bytes".to_string(),
+            ));
+        }
+        Self::from_rs(
+            signature[..32].try_into().expect("it is 32 bytes"),
+            signature[32..64].try_into().expect("it is 32 bytes"),
+        )
+    }
+    /// The DER encoded signature
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl TryFrom<[u8; 64]> for DerSignature {
+    type Error = Error;
+    fn try_from(signature: [u8; 64]) -> Result<Self, Self::Error> {
+        Self::from_compact(&signature)
+    }
+}
+
+impl TryFrom<[u8; 65]> for DerSignature {
+    type Error = Error;
+    fn try_from(signature: [u8; 65]) -> Result<Self, Self::Error> {
+        Self::from_compact(&signature)
+    }
+}
+
+impl From<DerSignature> for Vec<u8> {
+    fn from(signature: DerSignature) -> Self {
+        signature.0
+    }
+}
+
+/// A single signature of a transaction, of the key type of its `SigningPubKey`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Signature {
+    /// A DER encoded secp256k1 signature
+    Secp256k1(DerSignature),
+    /// An ed25519 signature
+    Ed25519([u8; 64]),
+}
+
+impl Signature {
+    /// The signing algorithm of this signature
+    pub fn key_type(&self) -> KeyType {
+        match self {
+            Self::Secp256k1(_) => KeyType::Secp256k1,
+            Self::Ed25519(_) => KeyType::Ed25519,
+        }
+    }
+    /// The signature as serialized in `TxnSignature`
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Secp256k1(signature) => signature.as_bytes(),
+            Self::Ed25519(signature) => signature.as_slice(),
+        }
+    }
+}
+
+impl From<DerSignature> for Signature {
+    fn from(signature: DerSignature) -> Self {
+        Self::Secp256k1(signature)
+    }
+}
+
+impl From<Signature> for Vec<u8> {
+    fn from(signature: Signature) -> Self {
+        match signature {
+            Signature::Secp256k1(signature) => signature.into(),
+            Signature::Ed25519(signature) => signature.to_vec(),
+        }
+    }
+}
+
+/// DER encode an unsigned big endian integer
+///
+/// Leading zeros are stripped and a zero byte is prepended if the high bit is set (so it is not negative)
+fn der_integer(value: &[u8; 32]) -> Vec<u8> {
+    let leading_zeros = value.iter().take_while(|b| **b == 0).count();
+    let value = &value[leading_zeros..];
+    let pad = value[0] & 0x80 != 0;
+
+    let mut buf = Vec::with_capacity(3 + value.len());
+    buf.push(DER_INTEGER);
+    buf.push((value.len() + pad as usize) as u8);
+    if pad {
+        buf.push(0);
+    }
+    buf.extend_from_slice(value);
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn der_encode() {
+        let signature = DerSignature::from_rs([7_u8; 32], [7_u8; 32]).unwrap();
+        let expected = [
+            [0x30, 0x44, 0x02, 0x20].as_slice(),
+            &[7_u8; 32],
+            &[0x02, 0x20],
+            &[7_u8; 32],
+        ]
+        .concat();
+        assert_eq!(signature.as_bytes(), expected.as_slice());
+        // the recovery ID is ignored
+        assert_eq!(DerSignature::try_from([7_u8; 65]), Ok(signature.clone()));
+        assert_eq!(DerSignature::try_from([7_u8; 64]), Ok(signature));
+    }
+
+    #[test]
+    fn der_encode_minimal_integers() {
+        // high bit set is padded, leading zeros are stripped
+        let mut r = [0x80_u8; 32];
+        let mut s = [0_u8; 32];
+        s[31] = 1;
+        let signature = DerSignature::from_rs(r, s).unwrap();
+        let expected = [
+            [0x30, 0x26, 0x02, 0x21, 0x00].as_slice(),
+            &r,
+            &[0x02, 0x01, 0x01],
+        ]
+        .concat();
+        assert_eq!(signature.as_bytes(), expected.as_slice());
+
+        r[0] = 0;
+        r[1] = 0x7f;
+        let signature = DerSignature::from_rs(r, SECP256K1_HALF_ORDER).unwrap();
+        let expected = [
+            [0x30, 0x43, 0x02, 0x1f].as_slice(),
+            &r[1..],
+            &[0x02, 0x20],
+            &SECP256K1_HALF_ORDER,
+        ]
+        .concat();
+        assert_eq!(signature.as_bytes(), expected.as_slice());
+    }
+
+    #[test]
+    fn reject_invalid_signatures() {
+        let mut high_s = SECP256K1_HALF_ORDER;
+        high_s[31] += 1;
+        assert_eq!(
+            DerSignature::from_rs([7_u8; 32], high_s),
+            Err(Error::InvalidData(
+                "non-canonical signature (high 's' value)".to_string()
+            ))
+        );
+        assert!(DerSignature::from_rs([0_u8; 32], [7_u8; 32]).is_err());
+        assert!(DerSignature::from_rs(SECP256K1_ORDER, [7_u8; 32]).is_err());
+        assert!(DerSignature::from_rs([7_u8; 32], [0_u8; 32]).is_err());
+        assert!(DerSignature::from_compact(&[7_u8; 63]).is_err());
+    }
+}
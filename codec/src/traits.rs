@@ -1,6 +1,7 @@
 //! Codec traits
 
-use crate::Vec;
+use crate::{field::FieldId, Error, Vec};
+use alloc::{format, string::ToString};
 
 /// A self-descriptive field type, wraps a primitive typed value for specific context
 /// e.g. Destination vs. Account are different fields but both AccountIds types
@@ -18,6 +19,10 @@ pub trait CodecField: BinarySerialize {
     fn is_signing_field(&self) -> bool;
     /// Return the inner value of the field
     fn inner(&self) -> &dyn BinarySerialize;
+    /// The field ID i.e. (type code, field code) which also determines canonical field order
+    fn field_id(&self) -> FieldId {
+        FieldId::new(self.type_code(), self.field_code())
+    }
 }
 
 /// Converts a codec type into its constituent fields
@@ -29,31 +34,96 @@ pub trait CodecToFields {
 pub trait BinarySerialize {
     /// Binary serialize `self` according to the XRPL codec spec into the given buffer.
     /// `for_signing` indicates whether the result is for signing or not
-    fn binary_serialize_to(&self, _buf: &mut Vec<u8>, for_signing: bool);
+    fn binary_serialize_to(&self, _buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error>;
     /// Binary serialize `self` according to the XRPL codec spec.
     /// `for_signing` indicates whether the result is for signing or not
-    fn binary_serialize(&self, for_signing: bool) -> Vec<u8> {
+    fn binary_serialize(&self, for_signing: bool) -> Result<Vec<u8>, Error> {
         let mut buf = Vec::<u8>::default();
-        self.binary_serialize_to(&mut buf, for_signing);
+        self.binary_serialize_to(&mut buf, for_signing)?;
+
+        Ok(buf)
+    }
+}
+
+pub trait BinaryDeserialize: Sized {
+    /// Binary deserialize an instance of `Self` according to the XRPL codec spec from the front of `buf`.
+    /// `buf` is advanced past the consumed bytes
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error>;
+    /// Binary deserialize an instance of `Self` according to the XRPL codec spec.
+    /// Fails if `data` is not consumed entirely
+    fn binary_deserialize(mut data: &[u8]) -> Result<Self, Error> {
+        let value = Self::binary_deserialize_from(&mut data)?;
+        if !data.is_empty() {
+            return Err(Error::InvalidData(format!(
+                "{} unexpected trailing bytes",
+                data.len()
+            )));
+        }
+
+        Ok(value)
+    }
+}
 
-        buf
+impl BinarySerialize for u8 {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
+        buf.push(*self);
+        Ok(())
     }
 }
 
 impl BinarySerialize for u16 {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
         buf.extend_from_slice(&self.to_be_bytes());
+        Ok(())
     }
 }
 
 impl BinarySerialize for u32 {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
         buf.extend_from_slice(&self.to_be_bytes());
+        Ok(())
     }
 }
 
 impl BinarySerialize for u64 {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
         buf.extend_from_slice(&self.to_be_bytes());
+        Ok(())
+    }
+}
+
+impl<const N: usize> BinaryDeserialize for [u8; N] {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        if buf.len() < N {
+            return Err(Error::InvalidData("unexpected end of input".to_string()));
+        }
+        let (value, rest) = buf.split_at(N);
+        *buf = rest;
+
+        Ok(value.try_into().expect("it is N bytes"))
+    }
+}
+
+impl BinaryDeserialize for u8 {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        <[u8; 1]>::binary_deserialize_from(buf).map(u8::from_be_bytes)
+    }
+}
+
+impl BinaryDeserialize for u16 {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        <[u8; 2]>::binary_deserialize_from(buf).map(u16::from_be_bytes)
+    }
+}
+
+impl BinaryDeserialize for u32 {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        <[u8; 4]>::binary_deserialize_from(buf).map(u32::from_be_bytes)
+    }
+}
+
+impl BinaryDeserialize for u64 {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        <[u8; 8]>::binary_deserialize_from(buf).map(u64::from_be_bytes)
     }
 }
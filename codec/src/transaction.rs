@@ -3,16 +3,80 @@ use xrpl_codec_utils::Transaction;
 
 use crate::types::Hash256Type;
 use crate::{
+    address::XAddress,
     field::*,
-    traits::{BinarySerialize, CodecField, CodecToFields},
+    public_key::{KeyType, PublicKey, ED25519_PUBLIC_KEY_PREFIX},
+    signature::Signature,
+    traits::{BinaryDeserialize, BinarySerialize, CodecField, CodecToFields},
     types::{
-        AccountIdType, AmountType, BlobType, STArrayType, SignerEntryType, UInt16Type, UInt32Type,
+        AccountIdType, AmountType, BlobType, STArrayType, SignerEntryType, SignerType, UInt16Type,
+        UInt32Type,
     },
-    Vec,
+    Error, Vec,
 };
+use alloc::{format, string::ToString};
+#[cfg(feature = "scale")]
+use codec::{Decode, Encode};
+#[cfg(feature = "scale")]
+use scale_info::TypeInfo;
+
+/// The `TxnSignature` of a single-signed tx with `signing_pub_key`
+///
+/// Fails if `signing_pub_key` is not set or its key type differs from `signature`
+fn txn_signature(
+    signing_pub_key: &SigningPubKey,
+    signature: Signature,
+) -> Result<TxnSignature, Error> {
+    let key_type = match signing_pub_key.0 .0.as_slice() {
+        [] => {
+            return Err(Error::InvalidData(
+                "signing public key is not set".to_string(),
+            ))
+        }
+        [ED25519_PUBLIC_KEY_PREFIX, ..] => KeyType::Ed25519,
+        _ => KeyType::Secp256k1,
+    };
+    if signature.key_type() != key_type {
+        return Err(Error::InvalidData(format!(
+            "{:?} signature does not match the {:?} signing public key",
+            signature.key_type(),
+            key_type
+        )));
+    }
+
+    Ok(TxnSignature(BlobType(signature.into())))
+}
+
+/// Build the `Signers` of a multi-signed tx from (public key, signature)s
+///
+/// Signers are sorted by account ID as required by the ledger. Fails if there are no signers or any account signs twice
+fn multi_signers(signatures: Vec<(PublicKey, Vec<u8>)>) -> Result<Signers, Error> {
+    if signatures.is_empty() {
+        return Err(Error::InvalidData("no multi-signatures".to_string()));
+    }
+    let mut signers: Vec<Signer> = signatures
+        .into_iter()
+        .map(|(public_key, signature)| {
+            Signer(SignerType(
+                Account(AccountIdType(public_key.account_id())),
+                SigningPubKey::from(public_key),
+                TxnSignature(BlobType(signature)),
+            ))
+        })
+        .collect();
+    // account IDs are compared as 160-bit big endian numbers
+    signers.sort_by_key(|Signer(SignerType(Account(AccountIdType(account)), ..))| *account);
+    if signers.windows(2).any(|pair| pair[0].0 .0 == pair[1].0 .0) {
+        return Err(Error::InvalidData("duplicate multi-signer".to_string()));
+    }
+
+    Ok(Signers(STArrayType(signers)))
+}
 
 /// An XRP payment tx
-#[derive(Transaction, Debug)]
+#[derive(Transaction, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo), scale_decode)]
+#[transaction_type(Payment)]
 pub struct Payment {
     /// common tx fields
     account: Account,
@@ -26,7 +90,8 @@ pub struct Payment {
     destination: Destination,
     /// set when signing
     signing_pub_key: SigningPubKey,
-    txn_signature: TxnSignature,
+    txn_signature: Option<TxnSignature>,
+    signers: Option<Signers>,
     source_tag: SourceTag,
 }
 
@@ -51,7 +116,7 @@ impl Payment {
         ticket_sequence: u32,
         fee: u64,
         source_tag: u32,
-        signing_pub_key: Option<[u8; 33]>,
+        signing_pub_key: Option<PublicKey>,
     ) -> Self {
         Self {
             account: Account(AccountIdType(account)),
@@ -66,20 +131,34 @@ impl Payment {
             // payment only
             amount: Amount(AmountType::Drops(amount)),
             destination: Destination(AccountIdType(destination)),
-            signing_pub_key: signing_pub_key
-                .map(|pk| SigningPubKey(BlobType(pk.to_vec())))
-                .unwrap_or_default(),
-            txn_signature: Default::default(),
+            signing_pub_key: signing_pub_key.map(SigningPubKey::from).unwrap_or_default(),
+            txn_signature: None,
+            signers: None,
         }
     }
     /// Attach a signature to the transaction
-    pub fn attach_signature(&mut self, signature: [u8; 65]) {
-        self.txn_signature = TxnSignature(BlobType(signature.to_vec()));
+    ///
+    /// Fails unless the signature is of the key type of `signing_pub_key`
+    pub fn attach_signature(&mut self, signature: impl Into<Signature>) -> Result<(), Error> {
+        self.txn_signature = Some(txn_signature(&self.signing_pub_key, signature.into())?);
+        Ok(())
+    }
+    /// Attach the (public key, signature)s of a multi-signed transaction, replacing any signature
+    pub fn attach_multi_signatures(
+        &mut self,
+        signatures: Vec<(PublicKey, Vec<u8>)>,
+    ) -> Result<(), Error> {
+        self.signers = Some(multi_signers(signatures)?);
+        self.signing_pub_key = Default::default();
+        self.txn_signature = None;
+        Ok(())
     }
 }
 
 /// An XRP payment tx with destination tag
-#[derive(Transaction, Debug)]
+#[derive(Transaction, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo), scale_decode)]
+#[transaction_type(Payment)]
 pub struct PaymentWithDestinationTag {
     /// common tx fields
     account: Account,
@@ -93,7 +172,8 @@ pub struct PaymentWithDestinationTag {
     destination: Destination,
     /// set when signing
     signing_pub_key: SigningPubKey,
-    txn_signature: TxnSignature,
+    txn_signature: Option<TxnSignature>,
+    signers: Option<Signers>,
     source_tag: SourceTag,
     destination_tag: DestinationTag,
 }
@@ -121,7 +201,7 @@ impl PaymentWithDestinationTag {
         fee: u64,
         source_tag: u32,
         destination_tag: u32,
-        signing_pub_key: Option<[u8; 33]>,
+        signing_pub_key: Option<PublicKey>,
     ) -> Self {
         Self {
             account: Account(AccountIdType(account)),
@@ -136,21 +216,128 @@ impl PaymentWithDestinationTag {
             // payment only
             amount: Amount(AmountType::Drops(amount)),
             destination: Destination(AccountIdType(destination)),
-            signing_pub_key: signing_pub_key
-                .map(|pk| SigningPubKey(BlobType(pk.to_vec())))
-                .unwrap_or_default(),
+            signing_pub_key: signing_pub_key.map(SigningPubKey::from).unwrap_or_default(),
             destination_tag: DestinationTag(UInt32Type(destination_tag)),
-            txn_signature: Default::default(),
+            txn_signature: None,
+            signers: None,
         }
     }
     /// Attach a signature to the transaction
-    pub fn attach_signature(&mut self, signature: [u8; 65]) {
-        self.txn_signature = TxnSignature(BlobType(signature.to_vec()));
+    ///
+    /// Fails unless the signature is of the key type of `signing_pub_key`
+    pub fn attach_signature(&mut self, signature: impl Into<Signature>) -> Result<(), Error> {
+        self.txn_signature = Some(txn_signature(&self.signing_pub_key, signature.into())?);
+        Ok(())
+    }
+    /// Attach the (public key, signature)s of a multi-signed transaction, replacing any signature
+    pub fn attach_multi_signatures(
+        &mut self,
+        signatures: Vec<(PublicKey, Vec<u8>)>,
+    ) -> Result<(), Error> {
+        self.signers = Some(multi_signers(signatures)?);
+        self.signing_pub_key = Default::default();
+        self.txn_signature = None;
+        Ok(())
+    }
+}
+
+/// An XRP payment tx to an X-address i.e. with a destination tag only if the X-address has one
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo))]
+pub enum XAddressPayment {
+    Payment(Payment),
+    PaymentWithDestinationTag(PaymentWithDestinationTag),
+}
+
+impl XAddressPayment {
+    /// Create a new XRP payment transaction to the X-address `destination`
+    ///
+    /// Fails if `destination` is not for the expected network i.e. a test network if `is_test_network`, otherwise
+    /// the main network. See `Payment::new` for the other parameters
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account: [u8; 20],
+        destination: &str,
+        is_test_network: bool,
+        amount: u64,
+        nonce: u32,
+        ticket_sequence: u32,
+        fee: u64,
+        source_tag: u32,
+        signing_pub_key: Option<PublicKey>,
+    ) -> Result<Self, Error> {
+        let x_address = XAddress::decode(destination)?;
+        if x_address.is_test_network != is_test_network {
+            return Err(Error::InvalidData(format!(
+                "X-address {} is not for the {} network",
+                destination,
+                if is_test_network { "test" } else { "main" }
+            )));
+        }
+        let XAddress {
+            account_id: AccountIdType(destination),
+            destination_tag,
+            ..
+        } = x_address;
+
+        Ok(match destination_tag {
+            Some(destination_tag) => {
+                Self::PaymentWithDestinationTag(PaymentWithDestinationTag::new(
+                    account,
+                    destination,
+                    amount,
+                    nonce,
+                    ticket_sequence,
+                    fee,
+                    source_tag,
+                    destination_tag,
+                    signing_pub_key,
+                ))
+            }
+            None => Self::Payment(Payment::new(
+                account,
+                destination,
+                amount,
+                nonce,
+                ticket_sequence,
+                fee,
+                source_tag,
+                signing_pub_key,
+            )),
+        })
+    }
+    /// Attach a signature to the transaction, see `Payment::attach_signature`
+    pub fn attach_signature(&mut self, signature: impl Into<Signature>) -> Result<(), Error> {
+        match self {
+            Self::Payment(tx) => tx.attach_signature(signature),
+            Self::PaymentWithDestinationTag(tx) => tx.attach_signature(signature),
+        }
+    }
+    /// Attach the (public key, signature)s of a multi-signed transaction, replacing any signature
+    pub fn attach_multi_signatures(
+        &mut self,
+        signatures: Vec<(PublicKey, Vec<u8>)>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::Payment(tx) => tx.attach_multi_signatures(signatures),
+            Self::PaymentWithDestinationTag(tx) => tx.attach_multi_signatures(signatures),
+        }
+    }
+}
+
+impl BinarySerialize for XAddressPayment {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        match self {
+            Self::Payment(tx) => tx.binary_serialize_to(buf, for_signing),
+            Self::PaymentWithDestinationTag(tx) => tx.binary_serialize_to(buf, for_signing),
+        }
     }
 }
 
 /// A non XRP alternative currency/token payment tx
-#[derive(Transaction, Debug)]
+#[derive(Transaction, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo), scale_decode)]
+#[transaction_type(Payment)]
 pub struct PaymentAltCurrency {
     /// common tx fields
     account: Account,
@@ -164,7 +351,8 @@ pub struct PaymentAltCurrency {
     destination: Destination,
     /// set when signing
     signing_pub_key: SigningPubKey,
-    txn_signature: TxnSignature,
+    txn_signature: Option<TxnSignature>,
+    signers: Option<Signers>,
     source_tag: SourceTag,
 }
 
@@ -189,7 +377,7 @@ impl PaymentAltCurrency {
         ticket_sequence: u32,
         fee: u64,
         source_tag: u32,
-        signing_pub_key: Option<[u8; 33]>,
+        signing_pub_key: Option<PublicKey>,
     ) -> Self {
         Self {
             account: Account(AccountIdType(account)),
@@ -204,20 +392,34 @@ impl PaymentAltCurrency {
             // payment only
             amount,
             destination: Destination(AccountIdType(destination)),
-            signing_pub_key: signing_pub_key
-                .map(|pk| SigningPubKey(BlobType(pk.to_vec())))
-                .unwrap_or_default(),
-            txn_signature: Default::default(),
+            signing_pub_key: signing_pub_key.map(SigningPubKey::from).unwrap_or_default(),
+            txn_signature: None,
+            signers: None,
         }
     }
     /// Attach a signature to the transaction
-    pub fn attach_signature(&mut self, signature: [u8; 65]) {
-        self.txn_signature = TxnSignature(BlobType(signature.to_vec()));
+    ///
+    /// Fails unless the signature is of the key type of `signing_pub_key`
+    pub fn attach_signature(&mut self, signature: impl Into<Signature>) -> Result<(), Error> {
+        self.txn_signature = Some(txn_signature(&self.signing_pub_key, signature.into())?);
+        Ok(())
+    }
+    /// Attach the (public key, signature)s of a multi-signed transaction, replacing any signature
+    pub fn attach_multi_signatures(
+        &mut self,
+        signatures: Vec<(PublicKey, Vec<u8>)>,
+    ) -> Result<(), Error> {
+        self.signers = Some(multi_signers(signatures)?);
+        self.signing_pub_key = Default::default();
+        self.txn_signature = None;
+        Ok(())
     }
 }
 
 /// A non XRP alternative currency/token payment tx with destination tag attribute
-#[derive(Transaction, Debug)]
+#[derive(Transaction, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo), scale_decode)]
+#[transaction_type(Payment)]
 pub struct PaymentAltCurrencyWithDestinationTag {
     /// common tx fields
     account: Account,
@@ -231,7 +433,8 @@ pub struct PaymentAltCurrencyWithDestinationTag {
     destination: Destination,
     /// set when signing
     signing_pub_key: SigningPubKey,
-    txn_signature: TxnSignature,
+    txn_signature: Option<TxnSignature>,
+    signers: Option<Signers>,
     source_tag: SourceTag,
     destination_tag: DestinationTag,
 }
@@ -259,7 +462,7 @@ impl PaymentAltCurrencyWithDestinationTag {
         fee: u64,
         source_tag: u32,
         destination_tag: u32,
-        signing_pub_key: Option<[u8; 33]>,
+        signing_pub_key: Option<PublicKey>,
     ) -> Self {
         Self {
             account: Account(AccountIdType(account)),
@@ -275,20 +478,34 @@ impl PaymentAltCurrencyWithDestinationTag {
             // payment only
             amount,
             destination: Destination(AccountIdType(destination)),
-            signing_pub_key: signing_pub_key
-                .map(|pk| SigningPubKey(BlobType(pk.to_vec())))
-                .unwrap_or_default(),
-            txn_signature: Default::default(),
+            signing_pub_key: signing_pub_key.map(SigningPubKey::from).unwrap_or_default(),
+            txn_signature: None,
+            signers: None,
         }
     }
     /// Attach a signature to the transaction
-    pub fn attach_signature(&mut self, signature: [u8; 65]) {
-        self.txn_signature = TxnSignature(BlobType(signature.to_vec()));
+    ///
+    /// Fails unless the signature is of the key type of `signing_pub_key`
+    pub fn attach_signature(&mut self, signature: impl Into<Signature>) -> Result<(), Error> {
+        self.txn_signature = Some(txn_signature(&self.signing_pub_key, signature.into())?);
+        Ok(())
+    }
+    /// Attach the (public key, signature)s of a multi-signed transaction, replacing any signature
+    pub fn attach_multi_signatures(
+        &mut self,
+        signatures: Vec<(PublicKey, Vec<u8>)>,
+    ) -> Result<(), Error> {
+        self.signers = Some(multi_signers(signatures)?);
+        self.signing_pub_key = Default::default();
+        self.txn_signature = None;
+        Ok(())
     }
 }
 
 /// An XRP SignerListSet tx
-#[derive(Transaction, Debug)]
+#[derive(Transaction, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo), scale_decode)]
+#[transaction_type(SignerListSet)]
 pub struct SignerListSet {
     /// common tx fields
     account: Account,
@@ -302,7 +519,8 @@ pub struct SignerListSet {
     signer_entries: SignerEntries,
     /// set when signing
     signing_pub_key: SigningPubKey,
-    txn_signature: TxnSignature,
+    txn_signature: Option<TxnSignature>,
+    signers: Option<Signers>,
     source_tag: SourceTag,
 }
 
@@ -326,7 +544,7 @@ impl SignerListSet {
         signer_quorum: u32,
         signer_entries: Vec<([u8; 20], u16)>,
         source_tag: u32,
-        signing_pub_key: Option<[u8; 33]>,
+        signing_pub_key: Option<PublicKey>,
     ) -> Self {
         Self {
             account: Account(AccountIdType(account)),
@@ -350,20 +568,51 @@ impl SignerListSet {
                     })
                     .collect(),
             )),
-            signing_pub_key: signing_pub_key
-                .map(|pk| SigningPubKey(BlobType(pk.to_vec())))
-                .unwrap_or_default(),
-            txn_signature: Default::default(),
+            signing_pub_key: signing_pub_key.map(SigningPubKey::from).unwrap_or_default(),
+            txn_signature: None,
+            signers: None,
         }
     }
+    /// The signer quorum required
+    pub fn signer_quorum(&self) -> u32 {
+        self.signer_quorum.0 .0
+    }
+    /// The (account, weight)s of the signer entries
+    pub fn signer_entries(&self) -> Vec<([u8; 20], u16)> {
+        self.signer_entries
+            .0
+             .0
+            .iter()
+            .map(
+                |SignerEntry(SignerEntryType(Account(AccountIdType(account)), weight))| {
+                    (*account, weight.0 .0)
+                },
+            )
+            .collect()
+    }
     /// Attach a signature to the transaction
-    pub fn attach_signature(&mut self, signature: [u8; 65]) {
-        self.txn_signature = TxnSignature(BlobType(signature.to_vec()));
+    ///
+    /// Fails unless the signature is of the key type of `signing_pub_key`
+    pub fn attach_signature(&mut self, signature: impl Into<Signature>) -> Result<(), Error> {
+        self.txn_signature = Some(txn_signature(&self.signing_pub_key, signature.into())?);
+        Ok(())
+    }
+    /// Attach the (public key, signature)s of a multi-signed transaction, replacing any signature
+    pub fn attach_multi_signatures(
+        &mut self,
+        signatures: Vec<(PublicKey, Vec<u8>)>,
+    ) -> Result<(), Error> {
+        self.signers = Some(multi_signers(signatures)?);
+        self.signing_pub_key = Default::default();
+        self.txn_signature = None;
+        Ok(())
     }
 }
 
 /// NFTokenCreateOffer tx
-#[derive(Transaction, Debug)]
+#[derive(Transaction, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo), scale_decode)]
+#[transaction_type(NFTokenCreateOffer)]
 pub struct NFTokenCreateOffer {
     /// common tx fields
     account: Account,
@@ -379,7 +628,8 @@ pub struct NFTokenCreateOffer {
     nftoken_id: NFTokenID,
     /// set when signing
     signing_pub_key: SigningPubKey,
-    txn_signature: TxnSignature,
+    txn_signature: Option<TxnSignature>,
+    signers: Option<Signers>,
 }
 
 impl NFTokenCreateOffer {
@@ -405,7 +655,7 @@ impl NFTokenCreateOffer {
         ticket_sequence: u32,
         fee: u64,
         source_tag: u32,
-        signing_pub_key: Option<[u8; 33]>,
+        signing_pub_key: Option<PublicKey>,
     ) -> Self {
         Self {
             account: Account(AccountIdType(account)),
@@ -422,20 +672,34 @@ impl NFTokenCreateOffer {
             amount: Amount(AmountType::Drops(amount)),
             destination: Destination(AccountIdType(destination)),
             nftoken_id: NFTokenID(Hash256Type(nftoken_id)),
-            signing_pub_key: signing_pub_key
-                .map(|pk| SigningPubKey(BlobType(pk.to_vec())))
-                .unwrap_or_default(),
-            txn_signature: Default::default(),
+            signing_pub_key: signing_pub_key.map(SigningPubKey::from).unwrap_or_default(),
+            txn_signature: None,
+            signers: None,
         }
     }
     /// Attach a signature to the transaction
-    pub fn attach_signature(&mut self, signature: [u8; 65]) {
-        self.txn_signature = TxnSignature(BlobType(signature.to_vec()));
+    ///
+    /// Fails unless the signature is of the key type of `signing_pub_key`
+    pub fn attach_signature(&mut self, signature: impl Into<Signature>) -> Result<(), Error> {
+        self.txn_signature = Some(txn_signature(&self.signing_pub_key, signature.into())?);
+        Ok(())
+    }
+    /// Attach the (public key, signature)s of a multi-signed transaction, replacing any signature
+    pub fn attach_multi_signatures(
+        &mut self,
+        signatures: Vec<(PublicKey, Vec<u8>)>,
+    ) -> Result<(), Error> {
+        self.signers = Some(multi_signers(signatures)?);
+        self.signing_pub_key = Default::default();
+        self.txn_signature = None;
+        Ok(())
     }
 }
 
 /// NFTokenAcceptOffer tx
-#[derive(Transaction, Debug)]
+#[derive(Transaction, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo), scale_decode)]
+#[transaction_type(NFTokenAcceptOffer)]
 pub struct NFTokenAcceptOffer {
     /// common tx fields
     account: Account,
@@ -448,7 +712,8 @@ pub struct NFTokenAcceptOffer {
     nftoken_sell_offer: NFTokenSellOffer,
     /// set when signing
     signing_pub_key: SigningPubKey,
-    txn_signature: TxnSignature,
+    txn_signature: Option<TxnSignature>,
+    signers: Option<Signers>,
 }
 
 impl NFTokenAcceptOffer {
@@ -470,7 +735,7 @@ impl NFTokenAcceptOffer {
         ticket_sequence: u32,
         fee: u64,
         source_tag: u32,
-        signing_pub_key: Option<[u8; 33]>,
+        signing_pub_key: Option<PublicKey>,
     ) -> Self {
         Self {
             account: Account(AccountIdType(account)),
@@ -482,15 +747,27 @@ impl NFTokenAcceptOffer {
             source_tag: SourceTag(UInt32Type(source_tag)),
             // NFTokenAcceptOffer only
             nftoken_sell_offer: NFTokenSellOffer(Hash256Type(nftoken_sell_offer)),
-            signing_pub_key: signing_pub_key
-                .map(|pk| SigningPubKey(BlobType(pk.to_vec())))
-                .unwrap_or_default(),
-            txn_signature: Default::default(),
+            signing_pub_key: signing_pub_key.map(SigningPubKey::from).unwrap_or_default(),
+            txn_signature: None,
+            signers: None,
         }
     }
     /// Attach a signature to the transaction
-    pub fn attach_signature(&mut self, signature: [u8; 65]) {
-        self.txn_signature = TxnSignature(BlobType(signature.to_vec()));
+    ///
+    /// Fails unless the signature is of the key type of `signing_pub_key`
+    pub fn attach_signature(&mut self, signature: impl Into<Signature>) -> Result<(), Error> {
+        self.txn_signature = Some(txn_signature(&self.signing_pub_key, signature.into())?);
+        Ok(())
+    }
+    /// Attach the (public key, signature)s of a multi-signed transaction, replacing any signature
+    pub fn attach_multi_signatures(
+        &mut self,
+        signatures: Vec<(PublicKey, Vec<u8>)>,
+    ) -> Result<(), Error> {
+        self.signers = Some(multi_signers(signatures)?);
+        self.signing_pub_key = Default::default();
+        self.txn_signature = None;
+        Ok(())
     }
 }
 
@@ -499,7 +776,11 @@ mod tests {
     use super::*;
     use crate::{
         field::{Account, SignerEntry, SignerWeight},
-        types::{AccountIdType, SignerEntryType, UInt16Type},
+        signature::DerSignature,
+        types::{
+            AccountIdType, CurrencyCodeType, IssuedAmountType, IssuedValueType, SignerEntryType,
+            UInt16Type,
+        },
     };
     use alloc::vec::Vec;
 
@@ -512,7 +793,7 @@ mod tests {
         let nonce = 1_u32;
         let ticket_number = 1_u32;
         let fee = 1_000; // 1000 drops
-        let signing_pub_key = [1_u8; 33];
+        let signing_pub_key = [2_u8; 33];
         let source_tag = 38_887_387_u32;
         let payment = Payment::new(
             account,
@@ -522,7 +803,7 @@ mod tests {
             ticket_number,
             fee,
             source_tag,
-            Some(signing_pub_key),
+            Some(PublicKey::try_from(signing_pub_key).unwrap()),
         );
 
         for chunk in payment.to_canonical_fields().chunks(2) {
@@ -545,7 +826,7 @@ mod tests {
         let fee = 1_000; // 1000 drops
         let nonce = 1_u32;
         let ticket_number = 1_u32;
-        let signing_pub_key = [1_u8; 33];
+        let signing_pub_key = [2_u8; 33];
         let signer_quorum = 3_u32;
         let mut signer_entries = Vec::<([u8; 20], u16)>::default();
         signer_entries.push(([1_u8; 20], 1_u16));
@@ -560,7 +841,7 @@ mod tests {
             signer_quorum,
             signer_entries,
             source_tag,
-            Some(signing_pub_key),
+            Some(PublicKey::try_from(signing_pub_key).unwrap()),
         );
 
         for chunk in signer_list_set.to_canonical_fields().chunks(2) {
@@ -583,7 +864,7 @@ mod tests {
         let fee = 1_000; // 1000 drops
         let nonce = 1_u32;
         let ticket_number = 1_u32;
-        let signing_pub_key = [1_u8; 33];
+        let signing_pub_key = [2_u8; 33];
         let signer_quorum = 3_u32;
         let mut signer_entries = Vec::<([u8; 20], u16)>::default();
         signer_entries.push(([1_u8; 20], 1_u16));
@@ -598,29 +879,52 @@ mod tests {
             signer_quorum,
             signer_entries.clone(),
             source_tag,
-            Some(signing_pub_key),
+            Some(PublicKey::try_from(signing_pub_key).unwrap()),
         );
 
-        let buf = signer_list_set.binary_serialize(true);
+        let buf = signer_list_set.binary_serialize(true).unwrap();
         // Construct the expected buf manually
         let mut expected_buf = Vec::<u8>::default();
         expected_buf.extend_from_slice(
             &TransactionType(UInt16Type(TransactionTypeCode::SignerListSet.code()))
-                .binary_serialize(true),
+                .binary_serialize(true)
+                .unwrap(),
         ); // TransactionType
-        expected_buf.extend_from_slice(&Flags(UInt32Type(0x8000_0000_u32)).binary_serialize(true)); // Flags
-        expected_buf.extend_from_slice(&SourceTag(UInt32Type(source_tag)).binary_serialize(true)); // SourceTag
-        expected_buf.extend_from_slice(&Sequence(UInt32Type(nonce)).binary_serialize(true)); // Nonce
+        expected_buf.extend_from_slice(
+            &Flags(UInt32Type(0x8000_0000_u32))
+                .binary_serialize(true)
+                .unwrap(),
+        ); // Flags
+        expected_buf.extend_from_slice(
+            &SourceTag(UInt32Type(source_tag))
+                .binary_serialize(true)
+                .unwrap(),
+        ); // SourceTag
         expected_buf
-            .extend_from_slice(&SignerQuorum(UInt32Type(signer_quorum)).binary_serialize(true)); // SignerQuorum
+            .extend_from_slice(&Sequence(UInt32Type(nonce)).binary_serialize(true).unwrap()); // Nonce
+        expected_buf.extend_from_slice(
+            &SignerQuorum(UInt32Type(signer_quorum))
+                .binary_serialize(true)
+                .unwrap(),
+        ); // SignerQuorum
+        expected_buf.extend_from_slice(
+            &TicketSequence(UInt32Type(ticket_number))
+                .binary_serialize(true)
+                .unwrap(),
+        ); // ticket_number
         expected_buf
-            .extend_from_slice(&TicketSequence(UInt32Type(ticket_number)).binary_serialize(true)); // ticket_number
-        expected_buf.extend_from_slice(&Fee(AmountType::Drops(fee)).binary_serialize(true)); // Fee
+            .extend_from_slice(&Fee(AmountType::Drops(fee)).binary_serialize(true).unwrap()); // Fee
         expected_buf.extend_from_slice(
-            &SigningPubKey(BlobType(signing_pub_key.to_vec())).binary_serialize(true),
+            &SigningPubKey(BlobType(signing_pub_key.to_vec()))
+                .binary_serialize(true)
+                .unwrap(),
         ); // SigningPubKey
-        expected_buf.extend_from_slice(&TxnSignature::default().binary_serialize(true)); // TxnSignature
-        expected_buf.extend_from_slice(&Account(AccountIdType(account)).binary_serialize(true)); // Account
+        expected_buf.extend_from_slice(&TxnSignature::default().binary_serialize(true).unwrap()); // TxnSignature
+        expected_buf.extend_from_slice(
+            &Account(AccountIdType(account))
+                .binary_serialize(true)
+                .unwrap(),
+        ); // Account
         let signer_entries = signer_entries
             .into_iter()
             .map(|(account, weight)| {
@@ -630,8 +934,11 @@ mod tests {
                 ))
             })
             .collect();
-        expected_buf
-            .extend_from_slice(&SignerEntries(STArrayType(signer_entries)).binary_serialize(true)); // SignerEntries
+        expected_buf.extend_from_slice(
+            &SignerEntries(STArrayType(signer_entries))
+                .binary_serialize(true)
+                .unwrap(),
+        ); // SignerEntries
         assert_eq!(buf, expected_buf);
     }
     #[test]
@@ -643,7 +950,7 @@ mod tests {
         let nonce = 1_u32;
         let ticket_number = 1_u32;
         let fee = 1_000; // 1000 drops
-        let signing_pub_key = [1_u8; 33];
+        let signing_pub_key = [2_u8; 33];
         let source_tag = 38_887_387_u32;
         let destination_tag = 12_112_289_u32;
         let payment = PaymentWithDestinationTag::new(
@@ -655,7 +962,7 @@ mod tests {
             fee,
             source_tag,
             destination_tag,
-            Some(signing_pub_key),
+            Some(PublicKey::try_from(signing_pub_key).unwrap()),
         );
 
         for chunk in payment.to_canonical_fields().chunks(2) {
@@ -682,7 +989,7 @@ mod tests {
         let sequence = 0_u32;
         let ticket_number = 1_u32;
         let fee = 1_000; // 1000 drops
-        let signing_pub_key = [1_u8; 33];
+        let signing_pub_key = [2_u8; 33];
         let source_tag = 38_887_387_u32;
         let nft_offer = NFTokenCreateOffer::new(
             account,
@@ -693,7 +1000,7 @@ mod tests {
             ticket_number,
             fee,
             source_tag,
-            Some(signing_pub_key),
+            Some(PublicKey::try_from(signing_pub_key).unwrap()),
         );
 
         for chunk in nft_offer.to_canonical_fields().chunks(2) {
@@ -718,7 +1025,7 @@ mod tests {
         let sequence = 0_u32;
         let ticket_number = 1_u32;
         let fee = 1_000; // 1000 drops
-        let signing_pub_key = [1_u8; 33];
+        let signing_pub_key = [2_u8; 33];
         let source_tag = 38_887_387_u32;
         let nft_accept_offer = NFTokenAcceptOffer::new(
             account,
@@ -727,7 +1034,7 @@ mod tests {
             ticket_number,
             fee,
             source_tag,
-            Some(signing_pub_key),
+            Some(PublicKey::try_from(signing_pub_key).unwrap()),
         );
 
         for chunk in nft_accept_offer.to_canonical_fields().chunks(2) {
@@ -743,4 +1050,478 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_Payment_decode() {
+        let mut payment = Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        let encoded = payment.binary_serialize(true).unwrap();
+        assert_eq!(Payment::decode(&encoded), Ok(payment.clone()));
+
+        payment
+            .attach_signature(DerSignature::try_from([7_u8; 65]).unwrap())
+            .unwrap();
+        let encoded = payment.binary_serialize(false).unwrap();
+        assert_eq!(Payment::decode(&encoded), Ok(payment));
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_PaymentAltCurrencyWithDestinationTag_decode() {
+        let amount = Amount(AmountType::Issued(
+            IssuedAmountType::from_issued_value(
+                IssuedValueType::from_mantissa_exponent(314, -2).unwrap(),
+                CurrencyCodeType::Standard(*b"AST"),
+                AccountIdType([3_u8; 20]),
+            )
+            .unwrap(),
+        ));
+        let mut payment = PaymentAltCurrencyWithDestinationTag::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            amount,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            12_124_121_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        payment
+            .attach_signature(DerSignature::try_from([7_u8; 65]).unwrap())
+            .unwrap();
+        let encoded = payment.binary_serialize(false).unwrap();
+        assert_eq!(
+            PaymentAltCurrencyWithDestinationTag::decode(&encoded),
+            Ok(payment)
+        );
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_SignerListSet_decode() {
+        let signer_list_set = SignerListSet::new(
+            [1_u8; 20],
+            1_000,
+            1_u32,
+            1_u32,
+            3_u32,
+            vec![([1_u8; 20], 1_u16), ([2_u8; 20], 2_u16)],
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        let encoded = signer_list_set.binary_serialize(true).unwrap();
+        assert_eq!(SignerListSet::decode(&encoded), Ok(signer_list_set));
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_NFTokenOffer_decode() {
+        let nft_offer = NFTokenCreateOffer::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            [3_u8; 32],
+            0_u64,
+            0_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        let encoded = nft_offer.binary_serialize(true).unwrap();
+        assert_eq!(NFTokenCreateOffer::decode(&encoded), Ok(nft_offer));
+
+        let nft_accept_offer = NFTokenAcceptOffer::new(
+            [1_u8; 20],
+            [3_u8; 32],
+            0_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        let encoded = nft_accept_offer.binary_serialize(true).unwrap();
+        assert_eq!(NFTokenAcceptOffer::decode(&encoded), Ok(nft_accept_offer));
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_Payment_decode_invalid() {
+        let payment = Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        let encoded = payment.binary_serialize(true).unwrap();
+        // TransactionType(0x12) is the first field
+        assert_eq!(encoded[..3], [0x12, 0x00, 0x00]);
+
+        // wrong TransactionType
+        let mut wrong_type = encoded.clone();
+        wrong_type[2] = TransactionTypeCode::SignerListSet.code() as u8;
+        assert!(Payment::decode(&wrong_type).is_err());
+
+        // duplicate field
+        let duplicate = [&encoded[..3], &encoded[..]].concat();
+        assert!(Payment::decode(&duplicate).is_err());
+
+        // out of order field
+        let out_of_order = [&encoded[3..8], &encoded[..3], &encoded[8..]].concat();
+        assert!(Payment::decode(&out_of_order).is_err());
+
+        // missing field i.e. the DestinationTag
+        assert!(PaymentWithDestinationTag::decode(&encoded).is_err());
+
+        // only the signature fields may be missing e.g. not the (non-signing) MasterSignature
+        #[derive(Transaction, Debug, Clone, PartialEq, Eq)]
+        #[transaction_type(Payment)]
+        struct PaymentWithMasterSignature {
+            account: Account,
+            transaction_type: TransactionType,
+            signing_pub_key: SigningPubKey,
+            master_signature: MasterSignature,
+            txn_signature: TxnSignature,
+        }
+        let payment_with_master_signature = PaymentWithMasterSignature {
+            account: Account(AccountIdType([1_u8; 20])),
+            transaction_type: TransactionTypeCode::Payment.into(),
+            signing_pub_key: PublicKey::try_from([2_u8; 33]).unwrap().into(),
+            master_signature: MasterSignature(BlobType(vec![5_u8; 64])),
+            txn_signature: TxnSignature(BlobType(vec![6_u8; 64])),
+        };
+        let encoded = payment_with_master_signature
+            .binary_serialize(false)
+            .unwrap();
+        assert_eq!(
+            PaymentWithMasterSignature::decode(&encoded),
+            Ok(payment_with_master_signature.clone())
+        );
+        let encoded = payment_with_master_signature
+            .binary_serialize(true)
+            .unwrap();
+        assert_eq!(
+            PaymentWithMasterSignature::decode(&encoded),
+            Err(Error::InvalidData(
+                "missing field MasterSignature".to_string()
+            ))
+        );
+
+        // unknown field i.e. the DestinationTag
+        let payment_with_destination_tag = PaymentWithDestinationTag::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            12_112_289_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        let encoded = payment_with_destination_tag.binary_serialize(true).unwrap();
+        assert!(Payment::decode(&encoded).is_err());
+
+        // truncated
+        assert!(PaymentWithDestinationTag::decode(&encoded[..encoded.len() - 1]).is_err());
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_Payment_attach_multi_signatures() {
+        let mut payment = Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        payment
+            .attach_signature(DerSignature::try_from([7_u8; 65]).unwrap())
+            .unwrap();
+
+        let (pk_a, pk_b) = (
+            PublicKey::try_from([2_u8; 33]).unwrap(),
+            PublicKey::try_from([3_u8; 33]).unwrap(),
+        );
+        let (account_a, account_b) = (pk_a.account_id(), pk_b.account_id());
+        // signers are ordered by account ID regardless of the order given
+        let (first, second) = if account_a < account_b {
+            (
+                (pk_a, account_a, vec![0xa_u8; 71]),
+                (pk_b, account_b, vec![0xb_u8; 72]),
+            )
+        } else {
+            (
+                (pk_b, account_b, vec![0xb_u8; 72]),
+                (pk_a, account_a, vec![0xa_u8; 71]),
+            )
+        };
+        payment
+            .attach_multi_signatures(vec![
+                (second.0, second.2.clone()),
+                (first.0, first.2.clone()),
+            ])
+            .unwrap();
+
+        // the signing encoding has an empty SigningPubKey and no signatures
+        let unsigned = Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            None,
+        );
+        assert_eq!(
+            payment.binary_serialize(true).unwrap(),
+            unsigned.binary_serialize(true).unwrap()
+        );
+
+        // the Signers array is last in canonical order
+        let encoded = payment.binary_serialize(false).unwrap();
+        let unsigned_encoded = unsigned.binary_serialize(false).unwrap();
+        assert_eq!(encoded[..unsigned_encoded.len()], unsigned_encoded);
+        let mut expected_signers = vec![0xf3]; // Signers
+        for (public_key, account, signature) in [first, second] {
+            expected_signers.extend_from_slice(&[0xe0, 0x10]); // Signer
+            expected_signers.extend_from_slice(&[0x73, 0x21]); // SigningPubKey
+            expected_signers.extend_from_slice(&public_key.to_bytes());
+            expected_signers.extend_from_slice(&[0x74, signature.len() as u8]); // TxnSignature
+            expected_signers.extend_from_slice(&signature);
+            expected_signers.extend_from_slice(&[0x81, 0x14]); // Account
+            expected_signers.extend_from_slice(&account);
+            expected_signers.push(0xe1);
+        }
+        expected_signers.push(0xf1);
+        assert_eq!(encoded[unsigned_encoded.len()..], expected_signers);
+
+        assert_eq!(Payment::decode(&encoded), Ok(payment.clone()));
+
+        // no signers, duplicate signer
+        assert!(payment.attach_multi_signatures(vec![]).is_err());
+        assert!(payment
+            .attach_multi_signatures(vec![(pk_a, vec![1_u8; 71]), (pk_a, vec![2_u8; 71])])
+            .is_err());
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_XAddressPayment_new() {
+        let account = [1_u8; 20];
+        // rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf
+        let destination = hex_literal::hex!("AA066C988C712815CC37AF71472B7CBBBD4E2A0A");
+        let payment = XAddressPayment::new(
+            account,
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+            false,
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        )
+        .unwrap();
+        assert_eq!(
+            payment,
+            XAddressPayment::Payment(Payment::new(
+                account,
+                destination,
+                5_000_000_u64,
+                1_u32,
+                1_u32,
+                1_000,
+                38_887_387_u32,
+                Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+            ))
+        );
+
+        let mut payment = XAddressPayment::new(
+            account,
+            "XVLhHMPHU98es4dbozjVtdWzVrDjtV8xvjGQTYPiAx6gwDC",
+            false,
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        )
+        .unwrap();
+        let mut expected = PaymentWithDestinationTag::new(
+            account,
+            destination,
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            1_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        payment
+            .attach_signature(DerSignature::try_from([7_u8; 65]).unwrap())
+            .unwrap();
+        expected
+            .attach_signature(DerSignature::try_from([7_u8; 65]).unwrap())
+            .unwrap();
+        assert_eq!(
+            payment.binary_serialize(false),
+            expected.binary_serialize(false)
+        );
+
+        assert!(XAddressPayment::new(
+            account,
+            "rGWrZyQqhTp9Xu7G5Pkayo7bXjH4k4QYpf",
+            false,
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            None,
+        )
+        .is_err());
+        // test network address on the main network and vice versa
+        for (address, is_test_network, network) in [
+            (
+                "TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE",
+                false,
+                "main",
+            ),
+            (
+                "XVLhHMPHU98es4dbozjVtdWzVrDjtV5fdx1mHp98tDMoQXb",
+                true,
+                "test",
+            ),
+        ] {
+            assert_eq!(
+                XAddressPayment::new(
+                    account,
+                    address,
+                    is_test_network,
+                    5_000_000_u64,
+                    1_u32,
+                    1_u32,
+                    1_000,
+                    38_887_387_u32,
+                    None,
+                ),
+                Err(Error::InvalidData(format!(
+                    "X-address {} is not for the {} network",
+                    address, network
+                )))
+            );
+        }
+        assert!(XAddressPayment::new(
+            account,
+            "TVE26TYGhfLC7tQDno7G8dGtxSkYQn49b3qD26PK7FcGSKE",
+            true,
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            None,
+        )
+        .is_ok());
+    }
+
+    #[cfg(feature = "scale")]
+    #[test]
+    #[allow(non_snake_case)]
+    fn test_Payment_scale_codec() {
+        let mut payment = Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        payment
+            .attach_signature(DerSignature::try_from([7_u8; 65]).unwrap())
+            .unwrap();
+
+        let encoded = payment.encode();
+        let decoded = <Payment as Decode>::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded, payment);
+        assert_eq!(
+            decoded.binary_serialize(false),
+            payment.binary_serialize(false)
+        );
+
+        // invariants are checked
+        let mut wrong_type = payment.clone();
+        wrong_type.transaction_type =
+            TransactionType(UInt16Type(TransactionTypeCode::SignerListSet.code()));
+        let encoded = wrong_type.encode();
+        assert!(<Payment as Decode>::decode(&mut encoded.as_slice()).is_err());
+        let mut too_many_drops = payment;
+        too_many_drops.amount = Amount(AmountType::Drops(100_000_000_000_000_001));
+        let encoded = too_many_drops.encode();
+        assert!(<Payment as Decode>::decode(&mut encoded.as_slice()).is_err());
+    }
+    #[test]
+    fn attach_signature_key_type() {
+        let payment = |signing_pub_key: Option<PublicKey>| {
+            Payment::new(
+                [1_u8; 20],
+                [2_u8; 20],
+                5_000_000_u64,
+                1_u32,
+                1_u32,
+                1_000,
+                38_887_387_u32,
+                signing_pub_key,
+            )
+        };
+        let der_signature = DerSignature::try_from([7_u8; 65]).unwrap();
+
+        let mut secp256k1_payment = payment(Some(PublicKey::try_from([2_u8; 33]).unwrap()));
+        assert_eq!(
+            secp256k1_payment.attach_signature(Signature::Ed25519([7_u8; 64])),
+            Err(Error::InvalidData(
+                "Ed25519 signature does not match the Secp256k1 signing public key".to_string()
+            ))
+        );
+        assert_eq!(secp256k1_payment.txn_signature, None);
+        assert!(secp256k1_payment
+            .attach_signature(der_signature.clone())
+            .is_ok());
+
+        let mut ed25519_payment = payment(Some(PublicKey::Ed25519([2_u8; 32])));
+        assert!(ed25519_payment
+            .attach_signature(der_signature.clone())
+            .is_err());
+        ed25519_payment
+            .attach_signature(Signature::Ed25519([7_u8; 64]))
+            .unwrap();
+        assert_eq!(
+            ed25519_payment.txn_signature,
+            Some(TxnSignature(BlobType([7_u8; 64].to_vec())))
+        );
+
+        assert_eq!(
+            payment(None).attach_signature(der_signature),
+            Err(Error::InvalidData(
+                "signing public key is not set".to_string()
+            ))
+        );
+    }
 }
@@ -2,83 +2,260 @@
 
 use crate::error::Error;
 use crate::{
-    field::{Account, SignerWeight},
-    traits::BinarySerialize,
+    definitions::field_by_id,
+    field::{
+        decode_vl_length, encode_vl_length, Account, FieldId, SignerWeight, SigningPubKey,
+        TxnSignature,
+    },
+    traits::{BinaryDeserialize, BinarySerialize},
     Vec,
 };
+use alloc::collections::BTreeMap;
 use alloc::format;
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
+#[cfg(feature = "scale")]
+use codec::{Compact, Decode, Encode, MaxEncodedLen};
+use core::{cmp::Ordering, fmt, ops::Neg, str::FromStr};
+#[cfg(feature = "scale")]
+use scale_info::TypeInfo;
 
+// type codes, ref - 'TYPES' in 'definitions.json'
+pub const UINT16_TYPE_CODE: u16 = 1;
+pub const UINT32_TYPE_CODE: u16 = 2;
+pub const UINT64_TYPE_CODE: u16 = 3;
+pub const HASH128_TYPE_CODE: u16 = 4;
+pub const HASH256_TYPE_CODE: u16 = 5;
+pub const AMOUNT_TYPE_CODE: u16 = 6;
+pub const BLOB_TYPE_CODE: u16 = 7;
 pub const ACCOUNT_ID_TYPE_CODE: u16 = 8;
+pub const NUMBER_TYPE_CODE: u16 = 9;
+pub const ST_OBJECT_TYPE_CODE: u16 = 14;
+pub const ST_ARRAY_TYPE_CODE: u16 = 15;
+pub const UINT8_TYPE_CODE: u16 = 16;
+pub const HASH160_TYPE_CODE: u16 = 17;
+pub const PATH_SET_TYPE_CODE: u16 = 18;
+pub const VECTOR256_TYPE_CODE: u16 = 19;
+pub const UINT96_TYPE_CODE: u16 = 20;
+pub const UINT192_TYPE_CODE: u16 = 21;
+pub const UINT384_TYPE_CODE: u16 = 22;
+pub const UINT512_TYPE_CODE: u16 = 23;
+pub const ISSUE_TYPE_CODE: u16 = 24;
+pub const XCHAIN_BRIDGE_TYPE_CODE: u16 = 25;
+pub const CURRENCY_TYPE_CODE: u16 = 26;
 
-#[derive(Debug, Clone)]
+/// Marks the end of an STObject, ref -> https://xrpl.org/serialization.html#object-fields
+const OBJECT_END_MARKER: u8 = 0xe1;
+/// Marks the end of an STArray, ref -> https://xrpl.org/serialization.html#array-fields
+const ARRAY_END_MARKER: u8 = 0xf1;
+/// Set on amounts which are issued tokens rather than XRP
+const ISSUED_MASK: u64 = 0x8000000000000000;
+/// Set on amounts which are positive
+const POSITIVE_MASK: u64 = 0x4000000000000000;
+/// Set on (non issued) amounts which are multi-purpose tokens (MPTs) rather than XRP
+const MPT_MASK: u64 = 0x2000000000000000;
+/// The account ID `rrrrrrrrrrrrrrrrrrrrBZbvji` which follows the issuer of an MPT issue
+const NO_ACCOUNT: [u8; 20] = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+/// Max. XRP supply in drops, ref - https://xrpl.org/docs/references/protocol/data-types/currency-formats#xrp-amounts
+pub(crate) const DROPS_MAX: u64 = 100_000_000_000_000_000;
+/// Separates the paths of a PathSet, ref -> https://xrpl.org/serialization.html#pathset-fields
+const PATH_SEPARATOR: u8 = 0xff;
+/// Marks the end of a PathSet
+const PATH_SET_END: u8 = 0x00;
+// path step type flags
+const PATH_STEP_ACCOUNT: u8 = 0x01;
+const PATH_STEP_CURRENCY: u8 = 0x10;
+const PATH_STEP_ISSUER: u8 = 0x20;
+/// Max. significant digits of a decimal string i.e. an issued value or number
+const MAX_SIGNIFICANT_DIGITS: usize = 16;
+/// Max. nesting depth of inner objects and arrays (as rippled)
+const MAX_NESTING_DEPTH: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NotPresentType;
 impl BinarySerialize for NotPresentType {
-    fn binary_serialize_to(&self, _buf: &mut Vec<u8>, _for_signing: bool) {}
+    fn binary_serialize_to(&self, _buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for NotPresentType {
+    fn binary_deserialize_from(_buf: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
+pub struct UInt8Type(pub u8);
+
+impl BinarySerialize for UInt8Type {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        self.0.binary_serialize_to(buf, for_signing)
+    }
+}
+
+impl BinaryDeserialize for UInt8Type {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        u8::binary_deserialize_from(buf).map(Self)
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
 pub struct UInt16Type(pub u16);
 
 impl BinarySerialize for UInt16Type {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
         self.0.binary_serialize_to(buf, for_signing)
     }
 }
 
-#[derive(Debug, Clone)]
+impl BinaryDeserialize for UInt16Type {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        u16::binary_deserialize_from(buf).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
 pub struct UInt32Type(pub u32);
 
 impl BinarySerialize for UInt32Type {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
         self.0.binary_serialize_to(buf, for_signing)
     }
 }
 
-#[derive(Debug, Clone)]
+impl BinaryDeserialize for UInt32Type {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        u32::binary_deserialize_from(buf).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
 pub struct UInt64Type(pub u64);
 
 impl BinarySerialize for UInt64Type {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
         self.0.binary_serialize_to(buf, for_signing)
     }
 }
 
-#[derive(Debug, Clone)]
+impl BinaryDeserialize for UInt64Type {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        u64::binary_deserialize_from(buf).map(Self)
+    }
+}
+
+/// Fixed width big-endian types which are (de)serialized as raw bytes
+macro_rules! fixed_bytes_type {
+    ($(#[$meta:meta])* $name:ident, $width:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
+        pub struct $name(pub [u8; $width]);
+        impl BinarySerialize for $name {
+            fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
+                buf.extend_from_slice(self.0.as_slice());
+                Ok(())
+            }
+        }
+
+        impl BinaryDeserialize for $name {
+            fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+                <[u8; $width]>::binary_deserialize_from(buf).map(Self)
+            }
+        }
+    };
+}
+
+fixed_bytes_type!(Hash128Type, 16);
+fixed_bytes_type!(UInt96Type, 12);
+fixed_bytes_type!(
+    /// e.g. an MPTokenIssuanceID i.e. sequence (4 bytes) and issuer account (20 bytes)
+    UInt192Type,
+    24
+);
+fixed_bytes_type!(UInt384Type, 48);
+fixed_bytes_type!(UInt512Type, 64);
+
+/// rippled's name for the 160-bit type i.e. `Hash160`
+pub type UInt160Type = Hash160Type;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
 pub struct Hash160Type(pub [u8; 20]);
 impl BinarySerialize for Hash160Type {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
         buf.extend_from_slice(self.0.as_slice());
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for Hash160Type {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        <[u8; 20]>::binary_deserialize_from(buf).map(Self)
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
 pub struct Hash256Type(pub [u8; 32]);
 impl BinarySerialize for Hash256Type {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
         buf.extend_from_slice(self.0.as_slice());
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for Hash256Type {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        <[u8; 32]>::binary_deserialize_from(buf).map(Self)
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
 pub struct AccountIdType(pub [u8; 20]);
 impl BinarySerialize for AccountIdType {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
         buf.extend_from_slice(self.0.as_slice());
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for AccountIdType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        <[u8; 20]>::binary_deserialize_from(buf).map(Self)
     }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo))]
 pub struct BlobType(pub Vec<u8>);
 
 impl BinarySerialize for BlobType {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
         buf.extend_from_slice(self.0.as_slice());
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for BlobType {
+    /// A blob carries no length of its own (it is length prefixed at the field level)
+    /// so it consumes the remainder of `buf`
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        let blob = buf.to_vec();
+        *buf = &[];
+
+        Ok(Self(blob))
     }
 }
 
 /// Currency code, ref - https://xrpl.org/docs/references/protocol/data-types/currency-formats#currency-codes
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
 pub enum CurrencyCodeType {
     Standard([u8; 3]),
     NonStandard([u8; 20]),
@@ -95,7 +272,7 @@ impl CurrencyCodeType {
 }
 
 impl BinarySerialize for CurrencyCodeType {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
         // https://xrpl.org/docs/references/protocol/binary-format#currency-codes
         match self {
             CurrencyCodeType::NonStandard(payload) => buf.extend_from_slice(payload),
@@ -105,11 +282,142 @@ impl BinarySerialize for CurrencyCodeType {
                 buf.extend_from_slice(&[0u8; 5]);
             }
         }
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for CurrencyCodeType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        // https://xrpl.org/docs/references/protocol/binary-format#currency-codes
+        let payload = <[u8; 20]>::binary_deserialize_from(buf)?;
+        if payload[..12].iter().all(|b| *b == 0) && payload[15..].iter().all(|b| *b == 0) {
+            Ok(CurrencyCodeType::Standard(
+                payload[12..15].try_into().expect("it is 3 bytes"),
+            ))
+        } else {
+            Ok(CurrencyCodeType::NonStandard(payload))
+        }
+    }
+}
+
+/// A list of 256-bit hashes e.g. `Amendments`
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo))]
+pub struct Vector256Type(pub Vec<[u8; 32]>);
+
+impl BinarySerialize for Vector256Type {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
+        for hash in &self.0 {
+            buf.extend_from_slice(hash);
+        }
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for Vector256Type {
+    /// Length prefixed at the field level (like a blob) so it consumes the remainder of `buf`
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        if !buf.len().is_multiple_of(32) {
+            return Err(Error::InvalidData(format!(
+                "Vector256 length {} is not a multiple of 32",
+                buf.len()
+            )));
+        }
+        let mut hashes = Vec::<[u8; 32]>::default();
+        while !buf.is_empty() {
+            hashes.push(<[u8; 32]>::binary_deserialize_from(buf)?);
+        }
+
+        Ok(Self(hashes))
+    }
+}
+
+/// A currency without an issuer, XRP is encoded as all zeroes
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
+pub enum CurrencyType {
+    Xrp,
+    Issued(CurrencyCodeType),
+}
+
+impl BinarySerialize for CurrencyType {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        match self {
+            CurrencyType::Xrp => buf.extend_from_slice(&[0u8; 20]),
+            CurrencyType::Issued(currency) => {
+                // the all zero code is reserved for XRP
+                if !currency.is_valid() || *currency == CurrencyCodeType::Standard([0u8; 3]) {
+                    return Err(Error::InvalidData(format!(
+                        "invalid currency code: {:?}",
+                        currency
+                    )));
+                }
+                currency.binary_serialize_to(buf, for_signing)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for CurrencyType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        let currency = CurrencyCodeType::binary_deserialize_from(buf)?;
+        if currency == CurrencyCodeType::Standard([0u8; 3]) {
+            return Ok(CurrencyType::Xrp);
+        }
+        if !currency.is_valid() {
+            return Err(Error::InvalidData(format!(
+                "invalid currency code: {:?}",
+                currency
+            )));
+        }
+
+        Ok(CurrencyType::Issued(currency))
+    }
+}
+
+/// An asset i.e. XRP or an issued currency (currency code + issuer), as used by AMMs and bridges
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
+pub enum IssueType {
+    Xrp,
+    Issued(CurrencyCodeType, AccountIdType),
+}
+
+impl BinarySerialize for IssueType {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        match self {
+            IssueType::Xrp => CurrencyType::Xrp.binary_serialize_to(buf, for_signing),
+            IssueType::Issued(currency, issuer) => {
+                CurrencyType::Issued(currency.clone()).binary_serialize_to(buf, for_signing)?;
+                issuer.binary_serialize_to(buf, for_signing)
+            }
+        }
+    }
+}
+
+impl BinaryDeserialize for IssueType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        // MPT issues are (issuer, `NO_ACCOUNT`, sequence)
+        if buf.get(..20) != Some(&[0_u8; 20]) && buf.get(20..40) == Some(&NO_ACCOUNT) {
+            return Err(Error::InvalidData(
+                "MPT issues are not supported".to_string(),
+            ));
+        }
+        // the issuer is only present for issued currencies
+        match CurrencyType::binary_deserialize_from(buf)? {
+            CurrencyType::Xrp => Ok(IssueType::Xrp),
+            CurrencyType::Issued(currency) => Ok(IssueType::Issued(
+                currency,
+                AccountIdType::binary_deserialize_from(buf)?,
+            )),
+        }
     }
 }
 
 /// The value of Issued amount, ref - https://xrpl.org/docs/references/protocol/data-types/currency-formats#string-numbers
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo, MaxEncodedLen))]
 pub struct IssuedValueType {
     // fields are private intentionally. use IssuedValueType::from_mantissa_exponent()
     mantissa: i64,
@@ -117,11 +425,17 @@ pub struct IssuedValueType {
 }
 
 impl IssuedValueType {
+    // normalized ranges, ref - https://xrpl.org/docs/references/protocol/binary-format#token-amount-format
+    const MANTISSA_MIN: i64 = 1000000000000000;
+    const MANTISSA_MAX: i64 = 9999999999999999;
+    const EXPONENT_MIN: i8 = -96;
+    const EXPONENT_MAX: i8 = 80;
+
     /// Creates value from given mantissa and exponent. The created value will be normalized
     /// according to https://xrpl.org/docs/references/protocol/binary-format#token-amount-format. If the value
     /// cannot be represented, an error is returned.
     pub fn from_mantissa_exponent(mantissa: i64, exponent: i8) -> Result<Self, Error> {
-        Self { mantissa, exponent }.normalize()
+        Self::normalize(mantissa, exponent as i32)
     }
 
     pub fn zero() -> Self {
@@ -131,46 +445,228 @@ impl IssuedValueType {
         }
     }
 
-    /// Normalizes value into the ranges specified in https://xrpl.org/docs/references/protocol/binary-format#token-amount-format
-    fn normalize(self) -> Result<Self, Error> {
-        // rippled implementation: https://github.com/seelabs/rippled/blob/cecc0ad75849a1d50cc573188ad301ca65519a5b/src/ripple/protocol/impl/IOUAmount.cpp#L38
-        const MANTISSA_MIN: i64 = 1000000000000000;
-        const MANTISSA_MAX: i64 = 9999999999999999;
-        const EXPONENT_MIN: i8 = -96;
-        const EXPONENT_MAX: i8 = 80;
+    /// The (normalized) signed mantissa, 0 for zero
+    pub fn mantissa(&self) -> i64 {
+        self.mantissa
+    }
+
+    /// The (normalized) exponent, 0 for zero
+    pub fn exponent(&self) -> i8 {
+        self.exponent
+    }
+
+    /// Whether the value is zero
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Add `rhs` as rippled's `Number` (as used by `IOUAmount`) i.e. the digits of the operand with the smaller
+    /// exponent that are shifted out are kept as guard digits to round the sum to nearest (ties to even)
+    ///
+    /// Fails if the sum is too big to be represented, too small sums are zero
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, Error> {
+        if rhs.is_zero() {
+            return Ok(self.clone());
+        }
+        if self.is_zero() {
+            return Ok(rhs.clone());
+        }
+        if self.mantissa == -rhs.mantissa && self.exponent == rhs.exponent {
+            return Ok(Self::zero());
+        }
+
+        let (mut negative, mut mantissa, mut exponent) = (
+            self.mantissa < 0,
+            self.mantissa.unsigned_abs(),
+            self.exponent as i32,
+        );
+        let (rhs_negative, mut rhs_mantissa, mut rhs_exponent) = (
+            rhs.mantissa < 0,
+            rhs.mantissa.unsigned_abs(),
+            rhs.exponent as i32,
+        );
+        let mut guard = Guard::default();
+        while exponent < rhs_exponent {
+            guard.push(mantissa % 10);
+            mantissa /= 10;
+            exponent += 1;
+        }
+        while rhs_exponent < exponent {
+            guard.push(rhs_mantissa % 10);
+            rhs_mantissa /= 10;
+            rhs_exponent += 1;
+        }
+
+        let (min, max) = (Self::MANTISSA_MIN as u64, Self::MANTISSA_MAX as u64);
+        if negative == rhs_negative {
+            mantissa += rhs_mantissa;
+            if mantissa > max {
+                guard.push(mantissa % 10);
+                mantissa /= 10;
+                exponent += 1;
+            }
+            if guard.round_away(mantissa) {
+                mantissa += 1;
+                if mantissa > max {
+                    mantissa /= 10;
+                    exponent += 1;
+                }
+            }
+        } else {
+            // the operand with the larger magnitude gives the sign
+            if mantissa > rhs_mantissa {
+                mantissa -= rhs_mantissa;
+            } else {
+                mantissa = rhs_mantissa - mantissa;
+                negative = rhs_negative;
+            }
+            // the guard digits were subtracted too
+            while mantissa < min {
+                mantissa = mantissa * 10 - guard.pop();
+                exponent -= 1;
+            }
+            if guard.round_away(mantissa) {
+                mantissa -= 1;
+                if mantissa < min {
+                    mantissa *= 10;
+                    exponent -= 1;
+                }
+            }
+        }
+
+        Self::from_normalized(negative, mantissa, exponent)
+    }
+
+    /// Subtract `rhs` i.e. add its negation, see `checked_add`
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, Error> {
+        self.checked_add(&-rhs.clone())
+    }
+
+    /// Multiply by `rhs` as rippled's `Number` i.e. the exact product is rounded to nearest (ties to even)
+    ///
+    /// Fails if the product is too big to be represented, too small products are zero
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, Error> {
+        if self.is_zero() || rhs.is_zero() {
+            return Ok(Self::zero());
+        }
+
+        Self::round_normalize(
+            (self.mantissa < 0) != (rhs.mantissa < 0),
+            self.mantissa.unsigned_abs() as u128 * rhs.mantissa.unsigned_abs() as u128,
+            self.exponent as i32 + rhs.exponent as i32,
+        )
+    }
+
+    /// Divide by `rhs` as rippled's `Number` i.e. the quotient of the mantissas is truncated to 17-18 digits and
+    /// then rounded to nearest (ties to even)
+    ///
+    /// Fails if `rhs` is zero or the quotient is too big to be represented, too small quotients are zero
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self, Error> {
+        if rhs.is_zero() {
+            return Err(Error::InvalidData(
+                "Issued value division by zero".to_string(),
+            ));
+        }
+        if self.is_zero() {
+            return Ok(Self::zero());
+        }
+
+        Self::round_normalize(
+            (self.mantissa < 0) != (rhs.mantissa < 0),
+            self.mantissa.unsigned_abs() as u128 * 100_000_000_000_000_000
+                / rhs.mantissa.unsigned_abs() as u128,
+            self.exponent as i32 - rhs.exponent as i32 - 17,
+        )
+    }
+
+    /// Normalizes the (non-zero) `mantissa` * 10^`exponent` as rippled's `Number` i.e. rounding to nearest (ties
+    /// to even)
+    fn round_normalize(negative: bool, mantissa: u128, exponent: i32) -> Result<Self, Error> {
+        let (min, max) = (Self::MANTISSA_MIN as u128, Self::MANTISSA_MAX as u128);
+        let (mut mantissa, mut exponent) = (mantissa, exponent);
+        while mantissa < min {
+            mantissa *= 10;
+            exponent -= 1;
+        }
+        let mut guard = Guard::default();
+        while mantissa > max {
+            guard.push((mantissa % 10) as u64);
+            mantissa /= 10;
+            exponent += 1;
+        }
+        let mut mantissa = mantissa as u64;
+        if guard.round_away(mantissa) {
+            mantissa += 1;
+            if mantissa > max as u64 {
+                mantissa /= 10;
+                exponent += 1;
+            }
+        }
 
-        let mut exponent = self.exponent;
-        let (mut mantissa, negative) = match self.mantissa {
+        Self::from_normalized(negative, mantissa, exponent)
+    }
+
+    /// The value of a normalized `mantissa` (as rippled's `IOUAmount` from a `Number`)
+    ///
+    /// Fails if `exponent` is too big, too small exponents are zero
+    fn from_normalized(negative: bool, mantissa: u64, exponent: i32) -> Result<Self, Error> {
+        if exponent > Self::EXPONENT_MAX as i32 {
+            return Err(Error::OutOfRange(format!(
+                "Issued value too big: {}e{}",
+                mantissa, exponent
+            )));
+        }
+        if exponent < Self::EXPONENT_MIN as i32 {
+            return Ok(Self::zero());
+        }
+        let mantissa = mantissa as i64;
+
+        Ok(Self {
+            mantissa: if negative { -mantissa } else { mantissa },
+            exponent: exponent as i8,
+        })
+    }
+
+    /// Normalizes `mantissa` * 10^`exponent` into the ranges specified in https://xrpl.org/docs/references/protocol/binary-format#token-amount-format
+    ///
+    /// The exponent is wider than an `i8` to normalize the intermediate results of arithmetic
+    fn normalize(mantissa: i64, exponent: i32) -> Result<Self, Error> {
+        // rippled implementation: https://github.com/seelabs/rippled/blob/cecc0ad75849a1d50cc573188ad301ca65519a5b/src/ripple/protocol/impl/IOUAmount.cpp#L38
+        let too_big = || {
+            Error::OutOfRange(format!(
+                "Issued value too big to be normalized: {}e{}",
+                mantissa, exponent
+            ))
+        };
+        let mut exponent = exponent;
+        let (mut mantissa, negative) = match mantissa {
             0 => {
                 return Ok(Self::zero());
             }
-            1.. => (self.mantissa, false),
+            1.. => (mantissa, false),
             ..=-1 => (
-                self.mantissa.checked_neg().ok_or_else(|| {
+                mantissa.checked_neg().ok_or_else(|| {
                     Error::OutOfRange("Specified mantissa cannot be i64::MIN".to_string())
                 })?,
                 true,
             ),
         };
 
-        while mantissa < MANTISSA_MIN && exponent > EXPONENT_MIN {
+        while mantissa < Self::MANTISSA_MIN && exponent > Self::EXPONENT_MIN as i32 {
             mantissa *= 10;
             exponent -= 1;
         }
 
-        while mantissa > MANTISSA_MAX && exponent < EXPONENT_MAX {
+        while mantissa > Self::MANTISSA_MAX && exponent < Self::EXPONENT_MAX as i32 {
             mantissa /= 10;
             exponent += 1;
         }
 
-        if mantissa > MANTISSA_MAX || exponent > EXPONENT_MAX {
-            return Err(Error::OutOfRange(format!(
-                "Issued value too big to be normalized: {:?}",
-                self
-            )));
+        if mantissa > Self::MANTISSA_MAX || exponent > Self::EXPONENT_MAX as i32 {
+            return Err(too_big());
         }
 
-        if mantissa < MANTISSA_MIN || exponent < EXPONENT_MIN {
+        if mantissa < Self::MANTISSA_MIN || exponent < Self::EXPONENT_MIN as i32 {
             return Ok(Self::zero());
         }
 
@@ -178,33 +674,201 @@ impl IssuedValueType {
             mantissa = -mantissa;
         }
 
-        Ok(Self { mantissa, exponent })
+        Ok(Self {
+            mantissa,
+            exponent: exponent as i8,
+        })
+    }
+}
+
+/// The decimal digits shifted out of a mantissa, as rippled's `Number::Guard`
+#[derive(Default)]
+struct Guard {
+    /// Up to 16 digits, one per nibble, the last shifted out digit is the most significant
+    digits: u64,
+    /// Whether a non-zero digit was shifted out of `digits`
+    xbit: bool,
+}
+
+impl Guard {
+    /// Half of the guard digits i.e. 0.5
+    const HALF: u64 = 0x5000_0000_0000_0000;
+
+    /// Shift in the next less significant `digit` of the mantissa
+    fn push(&mut self, digit: u64) {
+        self.xbit = self.xbit || self.digits & 0xf != 0;
+        self.digits = (self.digits >> 4) | (digit << 60);
+    }
+    /// Shift out the most significant digit
+    fn pop(&mut self) -> u64 {
+        let digit = self.digits >> 60;
+        self.digits <<= 4;
+        digit
+    }
+    /// Whether `mantissa` is rounded away from zero i.e. to nearest, ties to even
+    fn round_away(&self, mantissa: u64) -> bool {
+        match self.digits.cmp(&Self::HALF) {
+            Ordering::Greater => true,
+            Ordering::Equal => self.xbit || mantissa & 1 == 1,
+            Ordering::Less => false,
+        }
+    }
+}
+
+/// Negation cannot overflow as the mantissa is normalized
+impl Neg for IssuedValueType {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            mantissa: -self.mantissa,
+            exponent: self.exponent,
+        }
+    }
+}
+
+impl PartialOrd for IssuedValueType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders by value
+impl Ord for IssuedValueType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering = self.mantissa.signum().cmp(&other.mantissa.signum());
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        // same sign and normalized i.e. a larger exponent is a larger magnitude
+        let magnitude =
+            (self.exponent, self.mantissa.abs()).cmp(&(other.exponent, other.mantissa.abs()));
+        if self.mantissa < 0 {
+            magnitude.reverse()
+        } else {
+            magnitude
+        }
     }
 }
 
 impl BinarySerialize for IssuedValueType {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
         // https://xrpl.org/docs/references/protocol/binary-format#token-amount-format
-        const ISSUED_MASK: u64 = 0x8000000000000000;
-        const POSITIVE_MASK: u64 = 0x4000000000000000;
-
         let (mantissa, positive) = match self.mantissa {
             0 => {
-                ISSUED_MASK.binary_serialize_to(buf, for_signing);
-                return;
+                return ISSUED_MASK.binary_serialize_to(buf, for_signing);
             }
             1.. => (self.mantissa as u64, true),
             ..=-1 => (-self.mantissa as u64, false),
         };
-        let exponent = (self.exponent + 97) as u64;
+        let exponent = (self.exponent as i16 + 97) as u64;
         let payload =
             ISSUED_MASK | (if positive { POSITIVE_MASK } else { 0 }) | mantissa | (exponent << 54);
-        payload.binary_serialize_to(buf, for_signing);
+        payload.binary_serialize_to(buf, for_signing)
+    }
+}
+
+impl BinaryDeserialize for IssuedValueType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        // https://xrpl.org/docs/references/protocol/binary-format#token-amount-format
+        const MANTISSA_MASK: u64 = 0x003F_FFFF_FFFF_FFFF;
+
+        let payload = u64::binary_deserialize_from(buf)?;
+        if payload & ISSUED_MASK == 0 {
+            return Err(Error::InvalidData(
+                "Issued value is missing the issued bit".to_string(),
+            ));
+        }
+        if payload == ISSUED_MASK {
+            return Ok(Self::zero());
+        }
+
+        let mantissa = (payload & MANTISSA_MASK) as i64;
+        let exponent = ((payload >> 54) & 0xff) as i16 - 97;
+        if !(Self::MANTISSA_MIN..=Self::MANTISSA_MAX).contains(&mantissa)
+            || !(Self::EXPONENT_MIN as i16..=Self::EXPONENT_MAX as i16).contains(&exponent)
+        {
+            return Err(Error::InvalidData(format!(
+                "Issued value is not canonical: {:#018x}",
+                payload
+            )));
+        }
+
+        Ok(Self {
+            mantissa: if payload & POSITIVE_MASK != 0 {
+                mantissa
+            } else {
+                -mantissa
+            },
+            exponent: exponent as i8,
+        })
+    }
+}
+
+/// Only normalized values are decoded
+#[cfg(feature = "scale")]
+impl Decode for IssuedValueType {
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        let (mantissa, exponent) = <(i64, i8)>::decode(input)?;
+        match Self::from_mantissa_exponent(mantissa, exponent) {
+            Ok(value) if value.mantissa == mantissa && value.exponent == exponent => Ok(value),
+            _ => Err("Issued value is not normalized".into()),
+        }
+    }
+}
+
+/// Parses the XRPL string-number format i.e. an optional sign, decimal point and exponent e.g. "3.14" or "-1.23e-5"
+///
+/// Fails rather than rounding if the value has more than 16 significant digits or is out of range
+impl FromStr for IssuedValueType {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (negative, mantissa, mut exponent) = parse_decimal(value)?;
+        if mantissa == 0 {
+            return Ok(Self::zero());
+        }
+        let mut mantissa = mantissa as i64;
+        while mantissa < Self::MANTISSA_MIN {
+            mantissa *= 10;
+            exponent = exponent.saturating_sub(1);
+        }
+        let exponent = i8::try_from(exponent)
+            .ok()
+            .filter(|e| (Self::EXPONENT_MIN..=Self::EXPONENT_MAX).contains(e))
+            .ok_or_else(|| Error::OutOfRange(format!("Issued value: {}", value)))?;
+
+        Ok(Self {
+            mantissa: if negative { -mantissa } else { mantissa },
+            exponent,
+        })
+    }
+}
+
+/// Formats as rippled i.e. plain decimal notation e.g. "3.14" or "-0.0000123", unless the exponent (of the
+/// normalized mantissa) is outside -25..=-5 then scientific notation e.g. "1e-81" or "-15e19"
+impl fmt::Display for IssuedValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.mantissa != 0 && self.exponent != 0 && !(-25..=-5).contains(&self.exponent) {
+            let digits = self.mantissa.to_string();
+            let significant = digits.trim_end_matches('0');
+            let exponent = self.exponent as i32 + (digits.len() - significant.len()) as i32;
+            return match exponent {
+                0 => f.write_str(significant),
+                _ => write!(f, "{}e{}", significant, exponent),
+            };
+        }
+        f.write_str(&decimal_string(
+            self.mantissa < 0,
+            self.mantissa.unsigned_abs(),
+            self.exponent as i32,
+        ))
     }
 }
 
 /// Amount of issued token. ref - https://xrpl.org/docs/references/protocol/data-types/currency-formats#token-amounts,
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo, MaxEncodedLen))]
 pub struct IssuedAmountType {
     // fields are private intentionally. use IssuedAmountType::from_issued_value()
     value: IssuedValueType,
@@ -229,79 +893,971 @@ impl IssuedAmountType {
             issuer,
         })
     }
+
+    pub fn value(&self) -> &IssuedValueType {
+        &self.value
+    }
+
+    pub fn currency(&self) -> &CurrencyCodeType {
+        &self.currency
+    }
+
+    pub fn issuer(&self) -> &AccountIdType {
+        &self.issuer
+    }
 }
 
 impl BinarySerialize for IssuedAmountType {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        // https://xrpl.org/docs/references/protocol/binary-format#amount-fields
+        self.value.binary_serialize_to(buf, for_signing)?;
+        self.currency.binary_serialize_to(buf, for_signing)?;
+        self.issuer.binary_serialize_to(buf, for_signing)
+    }
+}
+
+impl BinaryDeserialize for IssuedAmountType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
         // https://xrpl.org/docs/references/protocol/binary-format#amount-fields
-        self.value.binary_serialize_to(buf, for_signing);
-        self.currency.binary_serialize_to(buf, for_signing);
-        self.issuer.binary_serialize_to(buf, for_signing);
+        let value = IssuedValueType::binary_deserialize_from(buf)?;
+        let currency = CurrencyCodeType::binary_deserialize_from(buf)?;
+        let issuer = AccountIdType::binary_deserialize_from(buf)?;
+
+        Self::from_issued_value(value, currency, issuer)
+    }
+}
+
+#[cfg(feature = "scale")]
+impl Decode for IssuedAmountType {
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        Self::from_issued_value(
+            IssuedValueType::decode(input)?,
+            CurrencyCodeType::decode(input)?,
+            AccountIdType::decode(input)?,
+        )
+        .map_err(|_| "Issued amount cannot have invalid currency code".into())
     }
 }
 
 /// Amount type, ref - https://xrpl.org/docs/references/protocol/data-types/currency-formats#specifying-currency-amounts
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo, MaxEncodedLen))]
 pub enum AmountType {
     Issued(IssuedAmountType), // For tokens
     Drops(u64),               // For XRP
 }
 
 impl BinarySerialize for AmountType {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
         // https://xrpl.org/docs/references/protocol/binary-format#amount-fields
         match self {
             AmountType::Issued(issued_amount) => {
                 issued_amount.binary_serialize_to(buf, for_signing)
             }
             AmountType::Drops(drops_amount) => {
-                const POSITIVE_MASK: u64 = 0x4000000000000000;
                 buf.extend_from_slice((drops_amount | POSITIVE_MASK).to_be_bytes().as_slice());
+                Ok(())
             }
         }
     }
 }
 
-// TODO(surangap) - https://github.com/futureversecom/xrpl-tx-codec/issues/7
-#[derive(Debug, Clone)]
-pub struct SignerEntryType(pub Account, pub SignerWeight);
-impl BinarySerialize for SignerEntryType {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) {
-        // call in canonical order
-        self.1.binary_serialize_to(buf, _for_signing);
-        self.0.binary_serialize_to(buf, _for_signing);
+impl BinaryDeserialize for AmountType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        // https://xrpl.org/docs/references/protocol/binary-format#amount-fields
+        match buf.first() {
+            Some(b) if (*b as u64) << 56 & ISSUED_MASK != 0 => {
+                IssuedAmountType::binary_deserialize_from(buf).map(AmountType::Issued)
+            }
+            Some(b) if (*b as u64) << 56 & MPT_MASK != 0 => Err(Error::InvalidData(
+                "MPT amounts are not supported".to_string(),
+            )),
+            _ => {
+                let payload = u64::binary_deserialize_from(buf)?;
+                if payload & POSITIVE_MASK == 0 {
+                    return Err(Error::InvalidData(
+                        "XRP amount cannot be negative".to_string(),
+                    ));
+                }
+                let drops = payload & !POSITIVE_MASK;
+                if drops > DROPS_MAX {
+                    return Err(Error::OutOfRange(format!(
+                        "XRP amount exceeds max. supply: {}",
+                        drops
+                    )));
+                }
 
-        // Append the Object end here. Ref -> https://xrpl.org/serialization.html#object-fields
-        buf.push(0xe1);
+                Ok(AmountType::Drops(drops))
+            }
+        }
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct STArrayType<T>(pub Vec<T>);
-impl<T: BinarySerialize> BinarySerialize for STArrayType<T> {
-    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) {
-        // no order, serialize the way it is. Ref -> https://xrpl.org/serialization.html#array-fields
-        for item in &self.0 {
-            item.binary_serialize_to(buf, _for_signing);
+/// Fails if the drops exceed `DROPS_MAX`, as the derived enum encoding
+#[cfg(feature = "scale")]
+impl Decode for AmountType {
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        match input.read_byte()? {
+            0 => IssuedAmountType::decode(input).map(AmountType::Issued),
+            1 => match u64::decode(input)? {
+                drops if drops <= DROPS_MAX => Ok(AmountType::Drops(drops)),
+                _ => Err("XRP amount exceeds max. supply".into()),
+            },
+            _ => Err("invalid AmountType variant".into()),
         }
-        // Append the array end here. Ref -> https://xrpl.org/serialization.html#array-fields
-        buf.push(0xf1);
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::field::{Account, SignerEntry, SignerWeight};
+/// A step of a payment path, ref - https://xrpl.org/serialization.html#pathset-fields
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
+pub struct PathStepType {
+    pub account: Option<AccountIdType>,
+    pub currency: Option<CurrencyType>,
+    pub issuer: Option<AccountIdType>,
+}
 
-    #[test]
-    #[allow(non_snake_case)]
+impl BinarySerialize for PathStepType {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        let mut step_type = 0_u8;
+        if self.account.is_some() {
+            step_type |= PATH_STEP_ACCOUNT;
+        }
+        if self.currency.is_some() {
+            step_type |= PATH_STEP_CURRENCY;
+        }
+        if self.issuer.is_some() {
+            step_type |= PATH_STEP_ISSUER;
+        }
+        if step_type == 0 {
+            return Err(Error::InvalidData("empty path step".to_string()));
+        }
+
+        buf.push(step_type);
+        if let Some(account) = &self.account {
+            account.binary_serialize_to(buf, for_signing)?;
+        }
+        if let Some(currency) = &self.currency {
+            currency.binary_serialize_to(buf, for_signing)?;
+        }
+        if let Some(issuer) = &self.issuer {
+            issuer.binary_serialize_to(buf, for_signing)?;
+        }
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for PathStepType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        let step_type = u8::binary_deserialize_from(buf)?;
+        if step_type == 0
+            || step_type & !(PATH_STEP_ACCOUNT | PATH_STEP_CURRENCY | PATH_STEP_ISSUER) != 0
+        {
+            return Err(Error::InvalidData(format!(
+                "invalid path step type: {:#04x}",
+                step_type
+            )));
+        }
+
+        let mut step = Self::default();
+        if step_type & PATH_STEP_ACCOUNT != 0 {
+            step.account = Some(AccountIdType::binary_deserialize_from(buf)?);
+        }
+        if step_type & PATH_STEP_CURRENCY != 0 {
+            step.currency = Some(CurrencyType::binary_deserialize_from(buf)?);
+        }
+        if step_type & PATH_STEP_ISSUER != 0 {
+            step.issuer = Some(AccountIdType::binary_deserialize_from(buf)?);
+        }
+        Ok(step)
+    }
+}
+
+/// A set of (non-empty) payment paths
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo))]
+pub struct PathSetType(pub Vec<Vec<PathStepType>>);
+
+impl BinarySerialize for PathSetType {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        for (i, path) in self.0.iter().enumerate() {
+            if path.is_empty() {
+                return Err(Error::InvalidData("empty path".to_string()));
+            }
+            if i > 0 {
+                buf.push(PATH_SEPARATOR);
+            }
+            for step in path {
+                step.binary_serialize_to(buf, for_signing)?;
+            }
+        }
+        buf.push(PATH_SET_END);
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for PathSetType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        let mut paths = Vec::<Vec<PathStepType>>::default();
+        let mut path = Vec::<PathStepType>::default();
+        loop {
+            match buf.first() {
+                Some(&PATH_SET_END) | Some(&PATH_SEPARATOR) => {
+                    if path.is_empty() {
+                        return Err(Error::InvalidData("empty path".to_string()));
+                    }
+                    paths.push(core::mem::take(&mut path));
+                    let marker = buf[0];
+                    *buf = &buf[1..];
+                    if marker == PATH_SET_END {
+                        return Ok(Self(paths));
+                    }
+                }
+                Some(_) => path.push(PathStepType::binary_deserialize_from(buf)?),
+                None => {
+                    return Err(Error::InvalidData(
+                        "PathSet is missing the end marker".to_string(),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+/// A cross-chain bridge i.e. the door accounts and assets of the locking and issuing chains
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
+pub struct XChainBridgeType {
+    pub locking_chain_door: AccountIdType,
+    pub locking_chain_issue: IssueType,
+    pub issuing_chain_door: AccountIdType,
+    pub issuing_chain_issue: IssueType,
+}
+
+impl XChainBridgeType {
+    /// Door accounts are length prefixed like AccountID fields
+    const DOOR_LENGTH_PREFIX: u8 = 0x14;
+
+    fn door_deserialize_from(buf: &mut &[u8]) -> Result<AccountIdType, Error> {
+        if u8::binary_deserialize_from(buf)? != Self::DOOR_LENGTH_PREFIX {
+            return Err(Error::InvalidData(
+                "XChainBridge door account must be 20 bytes".to_string(),
+            ));
+        }
+        AccountIdType::binary_deserialize_from(buf)
+    }
+}
+
+impl BinarySerialize for XChainBridgeType {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        buf.push(Self::DOOR_LENGTH_PREFIX);
+        self.locking_chain_door
+            .binary_serialize_to(buf, for_signing)?;
+        self.locking_chain_issue
+            .binary_serialize_to(buf, for_signing)?;
+        buf.push(Self::DOOR_LENGTH_PREFIX);
+        self.issuing_chain_door
+            .binary_serialize_to(buf, for_signing)?;
+        self.issuing_chain_issue
+            .binary_serialize_to(buf, for_signing)
+    }
+}
+
+impl BinaryDeserialize for XChainBridgeType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            locking_chain_door: Self::door_deserialize_from(buf)?,
+            locking_chain_issue: IssueType::binary_deserialize_from(buf)?,
+            issuing_chain_door: Self::door_deserialize_from(buf)?,
+            issuing_chain_issue: IssueType::binary_deserialize_from(buf)?,
+        })
+    }
+}
+
+/// A decimal floating point number i.e. `mantissa` * 10^`exponent`
+///
+/// Serialized as the signed 64-bit mantissa followed by the signed 32-bit exponent
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
+pub struct NumberType {
+    pub mantissa: i64,
+    pub exponent: i32,
+}
+
+impl BinarySerialize for NumberType {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        (self.mantissa as u64).binary_serialize_to(buf, for_signing)?;
+        (self.exponent as u32).binary_serialize_to(buf, for_signing)
+    }
+}
+
+impl BinaryDeserialize for NumberType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        Ok(Self {
+            mantissa: u64::binary_deserialize_from(buf)? as i64,
+            exponent: u32::binary_deserialize_from(buf)? as i32,
+        })
+    }
+}
+
+// TODO(surangap) - https://github.com/futureversecom/xrpl-tx-codec/issues/7
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo, MaxEncodedLen))]
+pub struct SignerEntryType(pub Account, pub SignerWeight);
+impl BinarySerialize for SignerEntryType {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
+        // call in canonical order
+        self.1.binary_serialize_to(buf, _for_signing)?;
+        self.0.binary_serialize_to(buf, _for_signing)?;
+
+        // Append the Object end here. Ref -> https://xrpl.org/serialization.html#object-fields
+        buf.push(OBJECT_END_MARKER);
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for SignerEntryType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        // fields arrive in canonical order
+        let signer_weight = SignerWeight::binary_deserialize_from(buf)?;
+        let account = Account::binary_deserialize_from(buf)?;
+        if u8::binary_deserialize_from(buf)? != OBJECT_END_MARKER {
+            return Err(Error::InvalidData(
+                "SignerEntry is missing the object end marker".to_string(),
+            ));
+        }
+
+        Ok(Self(account, signer_weight))
+    }
+}
+
+/// A multi-signature i.e. the signing account, its public key and signature
+/// https://xrpl.org/docs/references/protocol/transactions/common-fields#signers-field
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo))]
+pub struct SignerType(pub Account, pub SigningPubKey, pub TxnSignature);
+impl BinarySerialize for SignerType {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
+        // call in canonical order, the signature is always serialized within a signer
+        self.1.binary_serialize_to(buf, false)?;
+        self.2.binary_serialize_to(buf, false)?;
+        self.0.binary_serialize_to(buf, false)?;
+
+        buf.push(OBJECT_END_MARKER);
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for SignerType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        // fields arrive in canonical order
+        let signing_pub_key = SigningPubKey::binary_deserialize_from(buf)?;
+        let txn_signature = TxnSignature::binary_deserialize_from(buf)?;
+        let account = Account::binary_deserialize_from(buf)?;
+        if u8::binary_deserialize_from(buf)? != OBJECT_END_MARKER {
+            return Err(Error::InvalidData(
+                "Signer is missing the object end marker".to_string(),
+            ));
+        }
+
+        Ok(Self(account, signing_pub_key, txn_signature))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo))]
+pub struct STArrayType<T>(pub Vec<T>);
+impl<T> Default for STArrayType<T> {
+    fn default() -> Self {
+        Self(Vec::default())
+    }
+}
+impl<T: BinarySerialize> BinarySerialize for STArrayType<T> {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
+        // no order, serialize the way it is. Ref -> https://xrpl.org/serialization.html#array-fields
+        for item in &self.0 {
+            item.binary_serialize_to(buf, _for_signing)?;
+        }
+        // Append the array end here. Ref -> https://xrpl.org/serialization.html#array-fields
+        buf.push(ARRAY_END_MARKER);
+        Ok(())
+    }
+}
+
+impl<T: BinaryDeserialize> BinaryDeserialize for STArrayType<T> {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        let mut items = Vec::<T>::default();
+        loop {
+            match buf.first() {
+                Some(&ARRAY_END_MARKER) => {
+                    *buf = &buf[1..];
+                    return Ok(Self(items));
+                }
+                Some(_) => items.push(T::binary_deserialize_from(buf)?),
+                None => {
+                    return Err(Error::InvalidData(
+                        "STArray is missing the array end marker".to_string(),
+                    ))
+                }
+            }
+        }
+    }
+}
+
+/// An inner object i.e. the value of an STObject field, terminated by the object end marker
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo))]
+pub struct STObjectType(pub STObject);
+impl BinarySerialize for STObjectType {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, _for_signing: bool) -> Result<(), Error> {
+        // signing only affects the top level object (as in rippled) so inner objects are serialized in full
+        self.0.binary_serialize_to(buf, false)?;
+        buf.push(OBJECT_END_MARKER);
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for STObjectType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        let object = STObject::binary_deserialize_from(buf)?;
+        if u8::binary_deserialize_from(buf)? != OBJECT_END_MARKER {
+            return Err(Error::InvalidData(
+                "STObject is missing the object end marker".to_string(),
+            ));
+        }
+        Ok(Self(object))
+    }
+}
+
+/// An item of an STArray whose inner object field is only known at runtime e.g. `Memo` in `Memos`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode, TypeInfo))]
+pub struct STArrayItemType(pub FieldId, pub STObjectType);
+impl BinarySerialize for STArrayItemType {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        self.0.encode(buf);
+        self.1.binary_serialize_to(buf, for_signing)
+    }
+}
+
+impl BinaryDeserialize for STArrayItemType {
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        let field_id = FieldId::decode(buf)?;
+        if field_id.type_code != ST_OBJECT_TYPE_CODE {
+            return Err(Error::InvalidData(format!(
+                "STArray item {:?} is not an STObject",
+                field_id
+            )));
+        }
+        Ok(Self(field_id, STObjectType::binary_deserialize_from(buf)?))
+    }
+}
+
+/// A dynamically typed field value
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo))]
+pub enum SerializedValue {
+    UInt8(UInt8Type),
+    UInt16(UInt16Type),
+    UInt32(UInt32Type),
+    UInt64(UInt64Type),
+    UInt96(UInt96Type),
+    UInt192(UInt192Type),
+    UInt384(UInt384Type),
+    UInt512(UInt512Type),
+    Hash128(Hash128Type),
+    Hash160(Hash160Type),
+    Hash256(Hash256Type),
+    Amount(AmountType),
+    Blob(BlobType),
+    AccountId(AccountIdType),
+    Number(NumberType),
+    PathSet(PathSetType),
+    Vector256(Vector256Type),
+    Issue(IssueType),
+    XChainBridge(XChainBridgeType),
+    Currency(CurrencyType),
+    STObject(STObject),
+    /// Array of (inner object field, inner object)
+    STArray(Vec<(FieldId, STObject)>),
+}
+
+impl SerializedValue {
+    /// The XRPL type code of the value
+    pub fn type_code(&self) -> u16 {
+        match self {
+            Self::UInt8(_) => UINT8_TYPE_CODE,
+            Self::UInt16(_) => UINT16_TYPE_CODE,
+            Self::UInt32(_) => UINT32_TYPE_CODE,
+            Self::UInt64(_) => UINT64_TYPE_CODE,
+            Self::UInt96(_) => UINT96_TYPE_CODE,
+            Self::UInt192(_) => UINT192_TYPE_CODE,
+            Self::UInt384(_) => UINT384_TYPE_CODE,
+            Self::UInt512(_) => UINT512_TYPE_CODE,
+            Self::Hash128(_) => HASH128_TYPE_CODE,
+            Self::Hash160(_) => HASH160_TYPE_CODE,
+            Self::Hash256(_) => HASH256_TYPE_CODE,
+            Self::Amount(_) => AMOUNT_TYPE_CODE,
+            Self::Blob(_) => BLOB_TYPE_CODE,
+            Self::AccountId(_) => ACCOUNT_ID_TYPE_CODE,
+            Self::Number(_) => NUMBER_TYPE_CODE,
+            Self::PathSet(_) => PATH_SET_TYPE_CODE,
+            Self::Vector256(_) => VECTOR256_TYPE_CODE,
+            Self::Issue(_) => ISSUE_TYPE_CODE,
+            Self::XChainBridge(_) => XCHAIN_BRIDGE_TYPE_CODE,
+            Self::Currency(_) => CURRENCY_TYPE_CODE,
+            Self::STObject(_) => ST_OBJECT_TYPE_CODE,
+            Self::STArray(_) => ST_ARRAY_TYPE_CODE,
+        }
+    }
+
+    /// Whether the value is length prefixed when serialized
+    pub fn is_variable_length(&self) -> bool {
+        matches!(
+            self,
+            Self::Blob(_) | Self::AccountId(_) | Self::Vector256(_)
+        )
+    }
+
+    /// Decode a value of the given type code from the front of `buf` (after its field header)
+    ///
+    /// `depth` is the nesting depth of the object containing the value
+    fn binary_deserialize_typed(
+        type_code: u16,
+        buf: &mut &[u8],
+        depth: usize,
+    ) -> Result<Self, Error> {
+        match type_code {
+            BLOB_TYPE_CODE | ACCOUNT_ID_TYPE_CODE | VECTOR256_TYPE_CODE => {
+                let (length, consumed) = decode_vl_length(buf)?;
+                *buf = &buf[consumed..];
+                if buf.len() < length {
+                    return Err(Error::InvalidData("unexpected end of input".to_string()));
+                }
+                let (mut data, rest) = buf.split_at(length);
+                *buf = rest;
+                let value = match type_code {
+                    BLOB_TYPE_CODE => Self::Blob(BlobType::binary_deserialize_from(&mut data)?),
+                    VECTOR256_TYPE_CODE => {
+                        Self::Vector256(Vector256Type::binary_deserialize_from(&mut data)?)
+                    }
+                    _ => Self::AccountId(AccountIdType::binary_deserialize(data)?),
+                };
+                Ok(value)
+            }
+            UINT8_TYPE_CODE => UInt8Type::binary_deserialize_from(buf).map(Self::UInt8),
+            UINT16_TYPE_CODE => UInt16Type::binary_deserialize_from(buf).map(Self::UInt16),
+            UINT32_TYPE_CODE => UInt32Type::binary_deserialize_from(buf).map(Self::UInt32),
+            UINT64_TYPE_CODE => UInt64Type::binary_deserialize_from(buf).map(Self::UInt64),
+            UINT96_TYPE_CODE => UInt96Type::binary_deserialize_from(buf).map(Self::UInt96),
+            UINT192_TYPE_CODE => UInt192Type::binary_deserialize_from(buf).map(Self::UInt192),
+            UINT384_TYPE_CODE => UInt384Type::binary_deserialize_from(buf).map(Self::UInt384),
+            UINT512_TYPE_CODE => UInt512Type::binary_deserialize_from(buf).map(Self::UInt512),
+            HASH128_TYPE_CODE => Hash128Type::binary_deserialize_from(buf).map(Self::Hash128),
+            HASH160_TYPE_CODE => Hash160Type::binary_deserialize_from(buf).map(Self::Hash160),
+            HASH256_TYPE_CODE => Hash256Type::binary_deserialize_from(buf).map(Self::Hash256),
+            AMOUNT_TYPE_CODE => AmountType::binary_deserialize_from(buf).map(Self::Amount),
+            NUMBER_TYPE_CODE => NumberType::binary_deserialize_from(buf).map(Self::Number),
+            PATH_SET_TYPE_CODE => PathSetType::binary_deserialize_from(buf).map(Self::PathSet),
+            ISSUE_TYPE_CODE => IssueType::binary_deserialize_from(buf).map(Self::Issue),
+            XCHAIN_BRIDGE_TYPE_CODE => {
+                XChainBridgeType::binary_deserialize_from(buf).map(Self::XChainBridge)
+            }
+            CURRENCY_TYPE_CODE => CurrencyType::binary_deserialize_from(buf).map(Self::Currency),
+            ST_OBJECT_TYPE_CODE => {
+                let object = STObject::binary_deserialize_nested(buf, depth + 1)?;
+                if u8::binary_deserialize_from(buf)? != OBJECT_END_MARKER {
+                    return Err(Error::InvalidData(
+                        "STObject is missing the object end marker".to_string(),
+                    ));
+                }
+                Ok(Self::STObject(object))
+            }
+            ST_ARRAY_TYPE_CODE => {
+                check_nesting_depth(depth + 1)?;
+                let mut items = Vec::<(FieldId, STObject)>::default();
+                loop {
+                    match buf.first() {
+                        Some(&ARRAY_END_MARKER) => {
+                            *buf = &buf[1..];
+                            return Ok(Self::STArray(items));
+                        }
+                        Some(_) => {
+                            let field_id = FieldId::decode(buf)?;
+                            match Self::binary_deserialize_typed(
+                                field_id.type_code,
+                                buf,
+                                depth + 1,
+                            )? {
+                                Self::STObject(object) => items.push((field_id, object)),
+                                _ => {
+                                    return Err(Error::InvalidData(format!(
+                                        "STArray item {:?} is not an STObject",
+                                        field_id
+                                    )))
+                                }
+                            }
+                        }
+                        None => {
+                            return Err(Error::InvalidData(
+                                "STArray is missing the array end marker".to_string(),
+                            ))
+                        }
+                    }
+                }
+            }
+            _ => Err(Error::InvalidData(format!(
+                "unsupported type code: {}",
+                type_code
+            ))),
+        }
+    }
+}
+
+impl BinarySerialize for SerializedValue {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        match self {
+            Self::UInt8(value) => value.binary_serialize_to(buf, for_signing),
+            Self::UInt16(value) => value.binary_serialize_to(buf, for_signing),
+            Self::UInt32(value) => value.binary_serialize_to(buf, for_signing),
+            Self::UInt64(value) => value.binary_serialize_to(buf, for_signing),
+            Self::UInt96(value) => value.binary_serialize_to(buf, for_signing),
+            Self::UInt192(value) => value.binary_serialize_to(buf, for_signing),
+            Self::UInt384(value) => value.binary_serialize_to(buf, for_signing),
+            Self::UInt512(value) => value.binary_serialize_to(buf, for_signing),
+            Self::Hash128(value) => value.binary_serialize_to(buf, for_signing),
+            Self::Hash160(value) => value.binary_serialize_to(buf, for_signing),
+            Self::Hash256(value) => value.binary_serialize_to(buf, for_signing),
+            Self::Amount(value) => value.binary_serialize_to(buf, for_signing),
+            Self::Blob(value) => value.binary_serialize_to(buf, for_signing),
+            Self::AccountId(value) => value.binary_serialize_to(buf, for_signing),
+            Self::Number(value) => value.binary_serialize_to(buf, for_signing),
+            Self::PathSet(value) => value.binary_serialize_to(buf, for_signing),
+            Self::Vector256(value) => value.binary_serialize_to(buf, for_signing),
+            Self::Issue(value) => value.binary_serialize_to(buf, for_signing),
+            Self::XChainBridge(value) => value.binary_serialize_to(buf, for_signing),
+            Self::Currency(value) => value.binary_serialize_to(buf, for_signing),
+            // signing only affects the top level object (as in rippled) so inner objects are serialized in full
+            Self::STObject(object) => {
+                object.binary_serialize_to(buf, false)?;
+                buf.push(OBJECT_END_MARKER);
+                Ok(())
+            }
+            Self::STArray(items) => {
+                for (field_id, object) in items {
+                    field_id.encode(buf);
+                    object.binary_serialize_to(buf, false)?;
+                    buf.push(OBJECT_END_MARKER);
+                }
+                buf.push(ARRAY_END_MARKER);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A dynamic XRPL object i.e. a set of fields serialized in canonical order
+///
+/// Can represent any transaction (or inner object) including types which have no struct in this crate
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "scale", derive(Encode, TypeInfo))]
+pub struct STObject(BTreeMap<FieldId, SerializedValue>);
+
+impl STObject {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the field `field_id` to `value`, returning any previous value
+    ///
+    /// Fails if the field is unknown or the type of `value` does not match the field's type
+    pub fn insert(
+        &mut self,
+        field_id: FieldId,
+        value: SerializedValue,
+    ) -> Result<Option<SerializedValue>, Error> {
+        if field_by_id(field_id).is_none() {
+            return Err(Error::InvalidData(format!("unknown field {:?}", field_id)));
+        }
+        if field_id.type_code != value.type_code() {
+            return Err(Error::InvalidData(format!(
+                "field {:?} cannot hold a value of type code {}",
+                field_id,
+                value.type_code()
+            )));
+        }
+        Ok(self.0.insert(field_id, value))
+    }
+
+    pub fn get(&self, field_id: &FieldId) -> Option<&SerializedValue> {
+        self.0.get(field_id)
+    }
+
+    pub fn remove(&mut self, field_id: &FieldId) -> Option<SerializedValue> {
+        self.0.remove(field_id)
+    }
+
+    /// Iterate the fields in canonical order
+    pub fn iter(&self) -> impl Iterator<Item = (&FieldId, &SerializedValue)> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl BinarySerialize for STObject {
+    fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
+        // the map is ordered by field ID i.e. canonical order
+        for (field_id, value) in self.0.iter() {
+            let is_signing_field = field_by_id(*field_id).is_none_or(|f| f.is_signing_field);
+            if for_signing && !is_signing_field {
+                continue;
+            }
+            field_id.encode(buf);
+            if value.is_variable_length() {
+                let mut data = value.binary_serialize(for_signing)?;
+                buf.extend_from_slice(&encode_vl_length(data.len())?);
+                buf.append(&mut data);
+            } else {
+                value.binary_serialize_to(buf, for_signing)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BinaryDeserialize for STObject {
+    /// Decodes fields until the end of `buf` or an object end marker (which is not consumed)
+    fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+        Self::binary_deserialize_nested(buf, 0)
+    }
+}
+
+impl STObject {
+    /// Decode an object at nesting `depth` (0 for the top level object)
+    fn binary_deserialize_nested(buf: &mut &[u8], depth: usize) -> Result<Self, Error> {
+        check_nesting_depth(depth)?;
+        let mut object = Self::default();
+        let mut last_field_id: Option<FieldId> = None;
+        while let Some(&header) = buf.first() {
+            if header == OBJECT_END_MARKER {
+                break;
+            }
+            let field_id = FieldId::decode(buf)?;
+            if field_id.field_code == 1
+                && (field_id.type_code == ST_OBJECT_TYPE_CODE
+                    || field_id.type_code == ST_ARRAY_TYPE_CODE)
+            {
+                return Err(Error::InvalidData(format!(
+                    "unexpected end marker {:?}",
+                    field_id
+                )));
+            }
+            if let Some(last_field_id) = last_field_id {
+                if field_id <= last_field_id {
+                    return Err(Error::InvalidData(format!(
+                        "field {:?} is duplicate or not in canonical order",
+                        field_id
+                    )));
+                }
+            }
+            last_field_id = Some(field_id);
+            if field_by_id(field_id).is_none() {
+                return Err(Error::InvalidData(format!("unknown field {:?}", field_id)));
+            }
+
+            let value = SerializedValue::binary_deserialize_typed(field_id.type_code, buf, depth)?;
+            object.0.insert(field_id, value);
+        }
+
+        Ok(object)
+    }
+}
+
+/// Fails if any field is unknown or holds a value of another type
+#[cfg(feature = "scale")]
+impl Decode for STObject {
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        Self::decode_nested(input, 0)
+    }
+}
+
+#[cfg(feature = "scale")]
+impl STObject {
+    /// Decode an object at nesting `depth` (encoded as a `BTreeMap<FieldId, SerializedValue>`)
+    fn decode_nested<I: codec::Input>(input: &mut I, depth: usize) -> Result<Self, codec::Error> {
+        check_nesting_depth(depth).map_err(|_| "STObject nesting is too deep")?;
+        let mut object = Self::default();
+        for _ in 0..Compact::<u32>::decode(input)?.0 {
+            let field_id = <FieldId as Decode>::decode(input)?;
+            let value = SerializedValue::decode_nested(input, depth)?;
+            object
+                .insert(field_id, value)
+                .map_err(|_| "STObject field is unknown or has the wrong type")?;
+        }
+        Ok(object)
+    }
+}
+
+#[cfg(feature = "scale")]
+impl Decode for SerializedValue {
+    fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+        Self::decode_nested(input, 0)
+    }
+}
+
+#[cfg(feature = "scale")]
+impl SerializedValue {
+    /// Decode a value contained by an object at nesting `depth`, as the derived enum encoding
+    fn decode_nested<I: codec::Input>(input: &mut I, depth: usize) -> Result<Self, codec::Error> {
+        let value = match input.read_byte()? {
+            0 => Self::UInt8(Decode::decode(input)?),
+            1 => Self::UInt16(Decode::decode(input)?),
+            2 => Self::UInt32(Decode::decode(input)?),
+            3 => Self::UInt64(Decode::decode(input)?),
+            4 => Self::UInt96(Decode::decode(input)?),
+            5 => Self::UInt192(Decode::decode(input)?),
+            6 => Self::UInt384(Decode::decode(input)?),
+            7 => Self::UInt512(Decode::decode(input)?),
+            8 => Self::Hash128(Decode::decode(input)?),
+            9 => Self::Hash160(Decode::decode(input)?),
+            10 => Self::Hash256(Decode::decode(input)?),
+            11 => Self::Amount(Decode::decode(input)?),
+            12 => Self::Blob(Decode::decode(input)?),
+            13 => Self::AccountId(Decode::decode(input)?),
+            14 => Self::Number(Decode::decode(input)?),
+            15 => Self::PathSet(Decode::decode(input)?),
+            16 => Self::Vector256(Decode::decode(input)?),
+            17 => Self::Issue(Decode::decode(input)?),
+            18 => Self::XChainBridge(Decode::decode(input)?),
+            19 => Self::Currency(Decode::decode(input)?),
+            20 => Self::STObject(STObject::decode_nested(input, depth + 1)?),
+            21 => {
+                check_nesting_depth(depth + 1).map_err(|_| "STArray nesting is too deep")?;
+                let mut items = Vec::new();
+                for _ in 0..Compact::<u32>::decode(input)?.0 {
+                    let field_id = <FieldId as Decode>::decode(input)?;
+                    items.push((field_id, STObject::decode_nested(input, depth + 2)?));
+                }
+                Self::STArray(items)
+            }
+            _ => return Err("invalid SerializedValue variant".into()),
+        };
+        Ok(value)
+    }
+}
+
+/// Fails if `depth` is beyond `MAX_NESTING_DEPTH`
+pub(crate) fn check_nesting_depth(depth: usize) -> Result<(), Error> {
+    if depth > MAX_NESTING_DEPTH {
+        return Err(Error::InvalidData(format!(
+            "nesting depth exceeds max. of {}",
+            MAX_NESTING_DEPTH
+        )));
+    }
+    Ok(())
+}
+
+/// Parse a decimal string e.g. "-1.23e-5" into (negative, mantissa, exponent)
+///
+/// The mantissa has no trailing zeros. Fails if there are more than `MAX_SIGNIFICANT_DIGITS` significant digits
+pub(crate) fn parse_decimal(value: &str) -> Result<(bool, u64, i32), Error> {
+    let invalid = || Error::InvalidData(format!("invalid decimal: {}", value));
+    let (negative, unsigned) = match value.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (decimal, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((decimal, exponent)) => (decimal, exponent.parse::<i32>().map_err(|_| invalid())?),
+        None => (unsigned, 0),
+    };
+    let (integer, fraction) = decimal.split_once('.').unwrap_or((decimal, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    let digits = [integer, fraction].concat();
+    let digits = digits.trim_start_matches('0');
+    let significant = digits.trim_end_matches('0');
+    if significant.is_empty() {
+        return Ok((negative, 0, 0));
+    }
+    if significant.len() > MAX_SIGNIFICANT_DIGITS {
+        return Err(Error::InvalidData(format!(
+            "{} has more than {} significant digits and would lose precision",
+            value, MAX_SIGNIFICANT_DIGITS
+        )));
+    }
+    let exponent =
+        exponent as i64 - fraction.len() as i64 + (digits.len() - significant.len()) as i64;
+    let exponent =
+        i32::try_from(exponent).map_err(|_| Error::OutOfRange(format!("exponent of {}", value)))?;
+
+    Ok((
+        negative,
+        significant.parse().expect("at most 16 digits"),
+        exponent,
+    ))
+}
+
+/// Format `mantissa` * 10^`exponent` in plain decimal notation e.g. "-0.0123"
+pub(crate) fn decimal_string(negative: bool, mantissa: u64, exponent: i32) -> String {
+    if mantissa == 0 {
+        return "0".to_string();
+    }
+    let digits = mantissa.to_string();
+    let significant = digits.trim_end_matches('0');
+    let exponent = exponent as i64 + (digits.len() - significant.len()) as i64;
+    // position of the decimal point within the significant digits
+    let point = significant.len() as i64 + exponent;
+
+    let mut decimal = String::new();
+    if negative {
+        decimal.push('-');
+    }
+    if exponent >= 0 {
+        decimal.push_str(significant);
+        decimal.extend(core::iter::repeat_n('0', exponent as usize));
+    } else if point > 0 {
+        let (integer, fraction) = significant.split_at(point as usize);
+        decimal.push_str(integer);
+        decimal.push('.');
+        decimal.push_str(fraction);
+    } else {
+        decimal.push_str("0.");
+        decimal.extend(core::iter::repeat_n('0', -point as usize));
+        decimal.push_str(significant);
+    }
+    decimal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{Account, SignerEntry, SignerWeight};
+    use crate::public_key::PublicKey;
+    use crate::signature::DerSignature;
+    use crate::transaction::{Payment, SignerListSet};
+
+    #[test]
+    #[allow(non_snake_case)]
     fn test_SignerEntryType() {
         let signer_entry_type = SignerEntryType(
             Account(AccountIdType([1_u8; 20])),
             SignerWeight(UInt16Type(1_u16)),
         );
-        let buf = signer_entry_type.binary_serialize(true);
+        let buf = signer_entry_type.binary_serialize(true).unwrap();
         // let signer_entry_field_id: u8 = 0xEB; // Typecode(14) | FieldCode(11) = 0xEB
         let account_field_id: u8 = 0x81; // Typecode(8) | Fieldcode(1) = 0x81(129)
         let signer_weight_field_id: u8 = 0x13; // Typecode(1) | Fieldcode(3) = 0x13(19)
@@ -331,7 +1887,7 @@ mod tests {
         }
         let st_array_type = STArrayType(signer_entries);
 
-        let buf = st_array_type.binary_serialize(true);
+        let buf = st_array_type.binary_serialize(true).unwrap();
         let signer_entry_field_id: u8 = 0xEB; // Typecode(14) | FieldCode(11) = 0xEB
         let account_field_id: u8 = 0x81; // Typecode(8) | Fieldcode(1) = 0x81(129)
         let signer_weight_field_id: u8 = 0x13; // Typecode(1) | Fieldcode(3) = 0x13(19)
@@ -353,4 +1909,877 @@ mod tests {
 
         assert_eq!(buf, expected_buf);
     }
+    #[test]
+    fn deserialize_primitives() {
+        assert_eq!(
+            UInt16Type::binary_deserialize(&[0x01, 0x02]),
+            Ok(UInt16Type(0x0102))
+        );
+        assert_eq!(
+            UInt32Type::binary_deserialize(&[0x01, 0x02, 0x03, 0x04]),
+            Ok(UInt32Type(0x01020304))
+        );
+        assert_eq!(
+            UInt64Type::binary_deserialize(&18_u64.to_be_bytes()),
+            Ok(UInt64Type(18))
+        );
+        assert_eq!(
+            Hash160Type::binary_deserialize(&[7_u8; 20]),
+            Ok(Hash160Type([7_u8; 20]))
+        );
+        assert_eq!(
+            Hash256Type::binary_deserialize(&[7_u8; 32]),
+            Ok(Hash256Type([7_u8; 32]))
+        );
+        assert_eq!(
+            AccountIdType::binary_deserialize(&[7_u8; 20]),
+            Ok(AccountIdType([7_u8; 20]))
+        );
+        assert_eq!(
+            BlobType::binary_deserialize(&[1, 2, 3]),
+            Ok(BlobType(vec![1, 2, 3]))
+        );
+        // truncated
+        assert!(UInt32Type::binary_deserialize(&[0x01, 0x02, 0x03]).is_err());
+        assert!(AccountIdType::binary_deserialize(&[7_u8; 19]).is_err());
+        // trailing data
+        assert!(UInt16Type::binary_deserialize(&[0x01, 0x02, 0x03]).is_err());
+    }
+    #[test]
+    fn deserialize_amounts() {
+        for amount in [
+            AmountType::Drops(0),
+            AmountType::Drops(5_000_000),
+            AmountType::Issued(
+                IssuedAmountType::from_issued_value(
+                    IssuedValueType::from_mantissa_exponent(-314, -2).unwrap(),
+                    CurrencyCodeType::Standard(*b"AST"),
+                    AccountIdType([3_u8; 20]),
+                )
+                .unwrap(),
+            ),
+            AmountType::Issued(
+                IssuedAmountType::from_issued_value(
+                    IssuedValueType::zero(),
+                    CurrencyCodeType::NonStandard([5_u8; 20]),
+                    AccountIdType([3_u8; 20]),
+                )
+                .unwrap(),
+            ),
+        ] {
+            let buf = amount.binary_serialize(true).unwrap();
+            assert_eq!(AmountType::binary_deserialize(&buf), Ok(amount));
+        }
+        // decode -> encode at the exponent bounds i.e. 9999999999999999e80 and -1000000000000000e-96
+        for payload in [
+            ISSUED_MASK | POSITIVE_MASK | ((80 + 97) << 54) | 9_999_999_999_999_999,
+            ISSUED_MASK | (1 << 54) | 1_000_000_000_000_000,
+        ] {
+            let value = IssuedValueType::binary_deserialize(&payload.to_be_bytes()).unwrap();
+            assert_eq!(
+                value.binary_serialize(true),
+                Ok(payload.to_be_bytes().to_vec())
+            );
+        }
+    }
+    #[test]
+    fn deserialize_invalid_amounts() {
+        // negative XRP
+        assert!(AmountType::binary_deserialize(&10_u64.to_be_bytes()).is_err());
+        // more XRP than exists
+        assert!(
+            AmountType::binary_deserialize(&(u64::MAX >> 3 | POSITIVE_MASK).to_be_bytes()).is_err()
+        );
+        // MPT amount i.e. 100 of MPT issuance 00000001030303..
+        let mpt_amount = [
+            &[0x60_u8][..],
+            &100_u64.to_be_bytes(),
+            &[0, 0, 0, 1],
+            &[3_u8; 20],
+        ]
+        .concat();
+        assert_eq!(
+            AmountType::binary_deserialize(&mpt_amount),
+            Err(Error::InvalidData(
+                "MPT amounts are not supported".to_string()
+            ))
+        );
+        // non-normalized mantissa
+        let mut buf = (ISSUED_MASK | POSITIVE_MASK | 5_u64 | (97_u64 << 54))
+            .to_be_bytes()
+            .to_vec();
+        CurrencyCodeType::Standard(*b"AST")
+            .binary_serialize_to(&mut buf, true)
+            .unwrap();
+        AccountIdType([3_u8; 20])
+            .binary_serialize_to(&mut buf, true)
+            .unwrap();
+        assert!(AmountType::binary_deserialize(&buf).is_err());
+        // 'XRP' is not a valid issued currency
+        let mut buf = IssuedValueType::from_mantissa_exponent(5, 0)
+            .unwrap()
+            .binary_serialize(true)
+            .unwrap();
+        CurrencyCodeType::Standard(*b"XRP")
+            .binary_serialize_to(&mut buf, true)
+            .unwrap();
+        AccountIdType([3_u8; 20])
+            .binary_serialize_to(&mut buf, true)
+            .unwrap();
+        assert!(AmountType::binary_deserialize(&buf).is_err());
+    }
+    #[test]
+    fn deserialize_currency_code() {
+        for currency in [
+            CurrencyCodeType::Standard(*b"AST"),
+            CurrencyCodeType::NonStandard([5_u8; 20]),
+        ] {
+            let buf = currency.binary_serialize(true).unwrap();
+            assert_eq!(CurrencyCodeType::binary_deserialize(&buf), Ok(currency));
+        }
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn deserialize_STArrayType() {
+        let st_array_type = STArrayType(
+            (1..=2)
+                .map(|i| {
+                    SignerEntry(SignerEntryType(
+                        Account(AccountIdType([i as u8; 20])),
+                        SignerWeight(UInt16Type(i as u16)),
+                    ))
+                })
+                .collect::<Vec<_>>(),
+        );
+        let buf = st_array_type.binary_serialize(true).unwrap();
+        assert_eq!(
+            STArrayType::<SignerEntry>::binary_deserialize(&buf),
+            Ok(st_array_type)
+        );
+        // missing array end
+        assert!(STArrayType::<SignerEntry>::binary_deserialize(&buf[..buf.len() - 1]).is_err());
+        // missing object end
+        let signer_entry_type = SignerEntryType(
+            Account(AccountIdType([1_u8; 20])),
+            SignerWeight(UInt16Type(1_u16)),
+        );
+        let buf = signer_entry_type.binary_serialize(true).unwrap();
+        assert!(SignerEntryType::binary_deserialize(&buf[..buf.len() - 1]).is_err());
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn STObject_round_trip_transaction() {
+        let mut payment = Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        payment
+            .attach_signature(DerSignature::try_from([7_u8; 65]).unwrap())
+            .unwrap();
+        let encoded = payment.binary_serialize(false).unwrap();
+
+        let object = STObject::binary_deserialize(&encoded).unwrap();
+        assert_eq!(
+            object.get(&FieldId::new(ACCOUNT_ID_TYPE_CODE, 3)), // Destination
+            Some(&SerializedValue::AccountId(AccountIdType([2_u8; 20])))
+        );
+        assert_eq!(object.binary_serialize(false).unwrap(), encoded);
+        // TxnSignature is excluded for signing
+        assert_eq!(
+            object.binary_serialize(true).unwrap(),
+            payment.binary_serialize(true).unwrap()
+        );
+
+        let signer_list_set = SignerListSet::new(
+            [1_u8; 20],
+            1_000,
+            1_u32,
+            1_u32,
+            3_u32,
+            vec![([1_u8; 20], 1_u16), ([2_u8; 20], 2_u16)],
+            38_887_387_u32,
+            Some(PublicKey::try_from([2_u8; 33]).unwrap()),
+        );
+        let encoded = signer_list_set.binary_serialize(true).unwrap();
+        let object = STObject::binary_deserialize(&encoded).unwrap();
+        match object.get(&FieldId::new(ST_ARRAY_TYPE_CODE, 4)) {
+            Some(SerializedValue::STArray(signer_entries)) => assert_eq!(signer_entries.len(), 2),
+            other => panic!("unexpected SignerEntries: {:?}", other),
+        }
+        assert_eq!(object.binary_serialize(true).unwrap(), encoded);
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn STObject_canonical_order() {
+        let mut object = STObject::new();
+        // insert out of canonical order
+        object
+            .insert(
+                FieldId::new(ACCOUNT_ID_TYPE_CODE, 1), // Account
+                SerializedValue::AccountId(AccountIdType([1_u8; 20])),
+            )
+            .unwrap();
+        object
+            .insert(
+                FieldId::new(UINT32_TYPE_CODE, 2), // Flags
+                SerializedValue::UInt32(UInt32Type(0x8000_0000_u32)),
+            )
+            .unwrap();
+        object
+            .insert(
+                FieldId::new(UINT16_TYPE_CODE, 2), // TransactionType
+                SerializedValue::UInt16(UInt16Type(3)),
+            )
+            .unwrap();
+
+        let mut expected = vec![0x12, 0x00, 0x03, 0x22, 0x80, 0x00, 0x00, 0x00, 0x81, 0x14];
+        expected.extend_from_slice(&[1_u8; 20]);
+        assert_eq!(object.binary_serialize(true).unwrap(), expected);
+
+        // type mismatch
+        assert!(object
+            .insert(
+                FieldId::new(UINT32_TYPE_CODE, 4),
+                SerializedValue::UInt16(UInt16Type(1))
+            )
+            .is_err());
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn STObject_decode_invalid() {
+        // duplicate field
+        assert!(STObject::binary_deserialize(&[0x12, 0x00, 0x03, 0x12, 0x00, 0x03]).is_err());
+        // out of order field
+        assert!(
+            STObject::binary_deserialize(&[0x22, 0x80, 0x00, 0x00, 0x00, 0x12, 0x00, 0x03])
+                .is_err()
+        );
+        // unknown type i.e. 13
+        assert!(STObject::binary_deserialize(&[0xd1, 0x00]).is_err());
+        // stray array end marker
+        assert!(STObject::binary_deserialize(&[0xf1]).is_err());
+        // inner object missing its end marker
+        assert!(STObject::binary_deserialize(&[0xeb, 0x13, 0x00, 0x01]).is_err());
+        // unknown field i.e. UInt32 field code 200
+        assert!(STObject::binary_deserialize(&[0x20, 0xc8, 0x00, 0x00, 0x00, 0x01]).is_err());
+        assert!(STObject::new()
+            .insert(
+                FieldId::new(UINT32_TYPE_CODE, 200),
+                SerializedValue::UInt32(UInt32Type(1))
+            )
+            .is_err());
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn STObject_nesting_depth() {
+        // `depth` Memo objects nested in each other
+        let nested = |depth: usize| [vec![0xea_u8; depth], vec![0xe1_u8; depth]].concat();
+        let too_deep = Err(Error::InvalidData(
+            "nesting depth exceeds max. of 10".to_string(),
+        ));
+        assert!(STObject::binary_deserialize(&nested(10)).is_ok());
+        assert_eq!(STObject::binary_deserialize(&nested(11)), too_deep);
+        assert_eq!(
+            STObject::binary_deserialize(&vec![0xea; 1_000_000]),
+            too_deep
+        );
+        // Memos arrays of Memo objects count each level
+        let nested_arrays =
+            |depth: usize| [[0xf9_u8, 0xea].repeat(depth), [0xe1_u8, 0xf1].repeat(depth)].concat();
+        assert!(STObject::binary_deserialize(&nested_arrays(5)).is_ok());
+        assert_eq!(STObject::binary_deserialize(&nested_arrays(6)), too_deep);
+        assert_eq!(
+            STObject::binary_deserialize(&[0xf9_u8, 0xea].repeat(500_000)),
+            too_deep
+        );
+    }
+    #[test]
+    fn fixed_width_types() {
+        fn round_trip<T: BinarySerialize + BinaryDeserialize + PartialEq + core::fmt::Debug>(
+            value: T,
+            expected: &[u8],
+        ) {
+            let buf = value.binary_serialize(true).unwrap();
+            assert_eq!(buf, expected);
+            assert_eq!(T::binary_deserialize(&buf), Ok(value));
+            assert!(T::binary_deserialize(&buf[..buf.len() - 1]).is_err());
+        }
+
+        round_trip(UInt8Type(0xab), &[0xab]);
+        round_trip(Hash128Type([0x11; 16]), &[0x11; 16]);
+        round_trip(UInt96Type([0x22; 12]), &[0x22; 12]);
+        round_trip::<UInt160Type>(Hash160Type([0x33; 20]), &[0x33; 20]);
+        round_trip(UInt192Type([0x44; 24]), &[0x44; 24]);
+        round_trip(UInt384Type([0x55; 48]), &[0x55; 48]);
+        round_trip(UInt512Type([0x66; 64]), &[0x66; 64]);
+        // -1 i.e. -1e15 * 10^-15
+        round_trip(
+            NumberType {
+                mantissa: -1_000_000_000_000_000,
+                exponent: -15,
+            },
+            &hex::decode("fffc72815b398000fffffff1").unwrap(),
+        );
+    }
+    #[test]
+    fn serialize_vector256() {
+        let vector = Vector256Type(vec![[0x01; 32], [0x02; 32]]);
+        let buf = vector.binary_serialize(true).unwrap();
+        assert_eq!(buf, [[0x01_u8; 32], [0x02_u8; 32]].concat());
+        assert_eq!(Vector256Type::binary_deserialize(&buf), Ok(vector));
+        assert_eq!(
+            Vector256Type::binary_deserialize(&[]),
+            Ok(Vector256Type::default())
+        );
+        // not a whole number of hashes
+        assert!(Vector256Type::binary_deserialize(&buf[..33]).is_err());
+    }
+    #[test]
+    fn serialize_currency_and_issue() {
+        let usd = CurrencyCodeType::Standard(*b"USD");
+        let issuer = AccountIdType(
+            hex::decode("b5f762798a53d543a014caf8b297cff8f2f937e8")
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        );
+
+        let buf = CurrencyType::Xrp.binary_serialize(true).unwrap();
+        assert_eq!(buf, [0_u8; 20]);
+        assert_eq!(
+            CurrencyType::binary_deserialize(&buf),
+            Ok(CurrencyType::Xrp)
+        );
+        let buf = CurrencyType::Issued(usd.clone())
+            .binary_serialize(true)
+            .unwrap();
+        assert_eq!(
+            hex::encode(&buf),
+            "0000000000000000000000005553440000000000"
+        );
+        assert_eq!(
+            CurrencyType::binary_deserialize(&buf),
+            Ok(CurrencyType::Issued(usd.clone()))
+        );
+
+        let buf = IssueType::Xrp.binary_serialize(true).unwrap();
+        assert_eq!(buf, [0_u8; 20]);
+        assert_eq!(IssueType::binary_deserialize(&buf), Ok(IssueType::Xrp));
+        let issue = IssueType::Issued(usd, issuer);
+        let buf = issue.binary_serialize(true).unwrap();
+        assert_eq!(
+            hex::encode(&buf),
+            "0000000000000000000000005553440000000000b5f762798a53d543a014caf8b297cff8f2f937e8"
+        );
+        assert_eq!(IssueType::binary_deserialize(&buf), Ok(issue));
+        // missing issuer
+        assert!(IssueType::binary_deserialize(&buf[..20]).is_err());
+        // MPT issue i.e. (issuer, no account, sequence)
+        let mpt_issue = [&[3_u8; 20][..], &NO_ACCOUNT, &[0, 0, 0, 1]].concat();
+        assert_eq!(
+            IssueType::binary_deserialize(&mpt_issue),
+            Err(Error::InvalidData(
+                "MPT issues are not supported".to_string()
+            ))
+        );
+
+        // "XRP" is not a valid issued currency code
+        let xrp_code = CurrencyType::Issued(CurrencyCodeType::Standard(*b"XRP"));
+        assert!(xrp_code.binary_serialize(true).is_err());
+        assert!(CurrencyType::binary_deserialize(
+            &CurrencyCodeType::Standard(*b"XRP")
+                .binary_serialize(true)
+                .unwrap()
+        )
+        .is_err());
+    }
+    #[test]
+    fn serialize_path_set() {
+        let account = AccountIdType([0x01; 20]);
+        let issuer = AccountIdType([0x02; 20]);
+        let usd = CurrencyType::Issued(CurrencyCodeType::Standard(*b"USD"));
+        let path_set = PathSetType(vec![
+            vec![PathStepType {
+                account: Some(account.clone()),
+                ..Default::default()
+            }],
+            vec![
+                PathStepType {
+                    currency: Some(usd.clone()),
+                    issuer: Some(issuer.clone()),
+                    ..Default::default()
+                },
+                PathStepType {
+                    currency: Some(CurrencyType::Xrp),
+                    ..Default::default()
+                },
+            ],
+        ]);
+        let buf = path_set.binary_serialize(true).unwrap();
+        let expected = [
+            &[0x01][..],
+            &account.0,
+            &[0xff],
+            &[0x30],
+            &usd.binary_serialize(true).unwrap(),
+            &issuer.0,
+            &[0x10],
+            &[0_u8; 20],
+            &[0x00],
+        ]
+        .concat();
+        assert_eq!(buf, expected);
+        assert_eq!(PathSetType::binary_deserialize(&buf), Ok(path_set));
+
+        // missing end marker
+        assert!(PathSetType::binary_deserialize(&buf[..buf.len() - 1]).is_err());
+        // empty path
+        assert!(PathSetType::binary_deserialize(&[0x00]).is_err());
+        assert!(PathSetType(vec![vec![]]).binary_serialize(true).is_err());
+        // unknown step type
+        assert!(PathSetType::binary_deserialize(&[0x02, 0x00]).is_err());
+        // empty step
+        assert!(PathSetType(vec![vec![PathStepType::default()]])
+            .binary_serialize(true)
+            .is_err());
+    }
+    #[test]
+    fn serialize_xchain_bridge() {
+        let bridge = XChainBridgeType {
+            locking_chain_door: AccountIdType([0x01; 20]),
+            locking_chain_issue: IssueType::Xrp,
+            issuing_chain_door: AccountIdType([0x02; 20]),
+            issuing_chain_issue: IssueType::Issued(
+                CurrencyCodeType::Standard(*b"USD"),
+                AccountIdType([0x03; 20]),
+            ),
+        };
+        let buf = bridge.binary_serialize(true).unwrap();
+        let expected = [
+            &[0x14][..],
+            &[0x01; 20],
+            &[0_u8; 20],
+            &[0x14],
+            &[0x02; 20],
+            &CurrencyCodeType::Standard(*b"USD")
+                .binary_serialize(true)
+                .unwrap(),
+            &[0x03; 20],
+        ]
+        .concat();
+        assert_eq!(buf, expected);
+        assert_eq!(XChainBridgeType::binary_deserialize(&buf), Ok(bridge));
+
+        // door account must be length prefixed
+        let mut invalid = buf.clone();
+        invalid[0] = 0x15;
+        assert!(XChainBridgeType::binary_deserialize(&invalid).is_err());
+    }
+    #[test]
+    #[allow(non_snake_case)]
+    fn STObject_round_trip_new_types() {
+        let mut object = STObject::new();
+        let values = [
+            // AssetScale
+            (
+                FieldId::new(UINT8_TYPE_CODE, 5),
+                SerializedValue::UInt8(UInt8Type(2)),
+            ),
+            // MPTokenIssuanceID
+            (
+                FieldId::new(UINT192_TYPE_CODE, 1),
+                SerializedValue::UInt192(UInt192Type([0x01; 24])),
+            ),
+            // Amendments
+            (
+                FieldId::new(VECTOR256_TYPE_CODE, 3),
+                SerializedValue::Vector256(Vector256Type(vec![[0x02; 32]])),
+            ),
+            // Asset
+            (
+                FieldId::new(ISSUE_TYPE_CODE, 3),
+                SerializedValue::Issue(IssueType::Xrp),
+            ),
+            // BaseAsset
+            (
+                FieldId::new(CURRENCY_TYPE_CODE, 1),
+                SerializedValue::Currency(CurrencyType::Xrp),
+            ),
+        ];
+        for (field_id, value) in values {
+            object.insert(field_id, value).unwrap();
+        }
+
+        let buf = object.binary_serialize(false).unwrap();
+        // canonical order, field headers are [field code, type code] as type codes are >= 16
+        let expected = [
+            "051002".to_string(),                 // AssetScale
+            format!("031320{}", "02".repeat(32)), // Amendments, VL 32
+            format!("0115{}", "01".repeat(24)),   // MPTokenIssuanceID
+            format!("0318{}", "00".repeat(20)),   // Asset
+            format!("011a{}", "00".repeat(20)),   // BaseAsset
+        ]
+        .concat();
+        assert_eq!(hex::encode(&buf), expected);
+        assert_eq!(STObject::binary_deserialize(&buf), Ok(object));
+    }
+
+    #[cfg(feature = "scale")]
+    #[test]
+    fn scale_codec() {
+        let amount = AmountType::Issued(
+            IssuedAmountType::from_issued_value(
+                IssuedValueType::from_mantissa_exponent(-314, -2).unwrap(),
+                CurrencyCodeType::Standard(*b"AST"),
+                AccountIdType([3_u8; 20]),
+            )
+            .unwrap(),
+        );
+        let encoded = amount.encode();
+        assert!(encoded.len() <= AmountType::max_encoded_len());
+        assert_eq!(AmountType::decode(&mut encoded.as_slice()), Ok(amount));
+        let encoded = AmountType::Drops(DROPS_MAX).encode();
+        assert_eq!(
+            AmountType::decode(&mut encoded.as_slice()),
+            Ok(AmountType::Drops(DROPS_MAX))
+        );
+
+        let mut object = STObject::new();
+        object
+            .insert(
+                FieldId::new(UINT32_TYPE_CODE, 4),
+                SerializedValue::UInt32(UInt32Type(1)),
+            )
+            .unwrap();
+        let encoded = object.encode();
+        assert_eq!(STObject::decode(&mut encoded.as_slice()), Ok(object));
+
+        // invariants are checked
+        let not_normalized = (314_i64, -2_i8).encode();
+        assert!(IssuedValueType::decode(&mut not_normalized.as_slice()).is_err());
+        let invalid_currency = (
+            IssuedValueType::zero(),
+            CurrencyCodeType::Standard(*b"XRP"),
+            AccountIdType([3_u8; 20]),
+        )
+            .encode();
+        assert!(IssuedAmountType::decode(&mut invalid_currency.as_slice()).is_err());
+        let too_many_drops = AmountType::Drops(DROPS_MAX + 1).encode();
+        assert!(AmountType::decode(&mut too_many_drops.as_slice()).is_err());
+        let wrong_type = BTreeMap::from([(
+            FieldId::new(UINT32_TYPE_CODE, 4),
+            SerializedValue::UInt16(UInt16Type(1)),
+        )])
+        .encode();
+        assert!(STObject::decode(&mut wrong_type.as_slice()).is_err());
+
+        // nesting depth is limited
+        let nested = |depth: usize| {
+            (0..depth).fold(STObject::new(), |inner, _| {
+                let mut object = STObject::new();
+                object
+                    .insert(
+                        FieldId::new(ST_OBJECT_TYPE_CODE, 10), // Memo
+                        SerializedValue::STObject(inner),
+                    )
+                    .unwrap();
+                object
+            })
+        };
+        let encoded = nested(10).encode();
+        assert_eq!(STObject::decode(&mut encoded.as_slice()), Ok(nested(10)));
+        let encoded = nested(11).encode();
+        assert!(STObject::decode(&mut encoded.as_slice()).is_err());
+        // as `nested` i.e. (length, Memo, STObject variant) without an inner most object
+        let too_deep = [4_u8, 14, 0, 10, 0, 20].repeat(100_000);
+        assert!(STObject::decode(&mut too_deep.as_slice()).is_err());
+    }
+
+    #[test]
+    fn issued_value_strings() {
+        for (value, expected) in [
+            ("3.14", "3.14"),
+            ("+5", "5"),
+            ("-1.23e-5", "-0.0000123"),
+            ("1E20", "1e20"),
+            ("000.5000", "0.5"),
+            ("12345678901234560000", "1234567890123456e4"),
+            ("1234567890123456", "1234567890123456"),
+            ("12345678901", "12345678901"),
+            ("123456789012", "123456789012"),
+            ("-1.5e20", "-15e19"),
+            ("0.0000000001234", "0.0000000001234"),
+            ("1e-11", "1e-11"),
+            ("1e-81", "1e-81"),
+            ("-9999999999999999e80", "-9999999999999999e80"),
+            ("-0.0", "0"),
+        ] {
+            let issued_value = value.parse::<IssuedValueType>().unwrap();
+            assert_eq!(issued_value.to_string(), expected);
+            assert_eq!(expected.parse(), Ok(issued_value));
+        }
+        assert_eq!(
+            "1.23e-5".parse::<IssuedValueType>(),
+            IssuedValueType::from_mantissa_exponent(123, -7)
+        );
+        assert_eq!(
+            "9999999999999999e80".parse::<IssuedValueType>(),
+            IssuedValueType::from_mantissa_exponent(9_999_999_999_999_999, 80)
+        );
+
+        // binary round trip at the exponent bounds
+        for (value, payload) in [
+            (
+                "9999999999999999e80",
+                ISSUED_MASK | POSITIVE_MASK | (177 << 54) | 9_999_999_999_999_999,
+            ),
+            ("-1e-81", ISSUED_MASK | (1 << 54) | 1_000_000_000_000_000),
+        ] {
+            let issued_value = value.parse::<IssuedValueType>().unwrap();
+            let encoded = issued_value.binary_serialize(true).unwrap();
+            assert_eq!(encoded, payload.to_be_bytes());
+            assert_eq!(
+                IssuedValueType::binary_deserialize(&encoded),
+                Ok(issued_value)
+            );
+        }
+
+        // exact round trip at the limits
+        for (mantissa, exponent) in [
+            (1_000_000_000_000_000, -96),
+            (-9_999_999_999_999_999, 80),
+            (1_234_567_890_123_456, -20),
+        ] {
+            let issued_value = IssuedValueType::from_mantissa_exponent(mantissa, exponent).unwrap();
+            assert_eq!(issued_value.to_string().parse(), Ok(issued_value));
+        }
+
+        // precision loss and out of range
+        assert!(matches!(
+            "1.2345678901234567".parse::<IssuedValueType>(),
+            Err(Error::InvalidData(_))
+        ));
+        assert!(matches!(
+            "1e97".parse::<IssuedValueType>(),
+            Err(Error::OutOfRange(_))
+        ));
+        assert!(matches!(
+            "1e-97".parse::<IssuedValueType>(),
+            Err(Error::OutOfRange(_))
+        ));
+        for invalid in ["", ".", "-", "e5", "1.2.3", "1e", "0x10", "1,5", " 1"] {
+            assert!(invalid.parse::<IssuedValueType>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn issued_value_arithmetic() {
+        let value = |v: &str| v.parse::<IssuedValueType>().unwrap();
+        let max = IssuedValueType::from_mantissa_exponent(9_999_999_999_999_999, 80).unwrap();
+
+        let number = |m: i64, e: i8| IssuedValueType::from_mantissa_exponent(m, e).unwrap();
+        let zero = IssuedValueType::zero;
+
+        // test vectors of rippled's 'Number_test.cpp'
+        let add_cases = [
+            (
+                number(1_000_000_000_000_000, -15),
+                number(6_555_555_555_555_555, -29),
+                number(1_000_000_000_000_066, -15),
+            ),
+            (
+                number(-1_000_000_000_000_000, -15),
+                number(-6_555_555_555_555_555, -29),
+                number(-1_000_000_000_000_066, -15),
+            ),
+            (
+                number(-1_000_000_000_000_000, -15),
+                number(6_555_555_555_555_555, -29),
+                number(-9_999_999_999_999_344, -16),
+            ),
+            (
+                number(-6_555_555_555_555_555, -29),
+                number(1_000_000_000_000_000, -15),
+                number(9_999_999_999_999_344, -16),
+            ),
+            (zero(), number(5, 0), number(5, 0)),
+            (
+                number(-9_999_999_999_999_999, -31),
+                number(1_000_000_000_000_000, -15),
+                number(9_999_999_999_999_990, -16),
+            ),
+        ];
+        for (x, y, z) in add_cases {
+            assert_eq!(x.checked_add(&y), Ok(z), "{} + {}", x, y);
+        }
+        let sub_cases = [
+            (
+                number(1_000_000_000_000_000, -15),
+                number(6_555_555_555_555_555, -29),
+                number(9_999_999_999_999_344, -16),
+            ),
+            (
+                number(6_555_555_555_555_555, -29),
+                number(1_000_000_000_000_000, -15),
+                number(-9_999_999_999_999_344, -16),
+            ),
+            (
+                number(1_000_000_000_000_000, -15),
+                number(1_000_000_000_000_000, -15),
+                zero(),
+            ),
+            (
+                number(1_000_000_000_000_000, -15),
+                number(1_000_000_000_000_001, -15),
+                number(-1_000_000_000_000_000, -30),
+            ),
+            (
+                number(1_000_000_000_000_001, -15),
+                number(1_000_000_000_000_000, -15),
+                number(1_000_000_000_000_000, -30),
+            ),
+            // the guard digit is kept on cancellation
+            (value("1"), value("0.9999999999999991"), value("9e-16")),
+        ];
+        for (x, y, z) in sub_cases {
+            assert_eq!(x.checked_sub(&y), Ok(z), "{} - {}", x, y);
+        }
+        let mul_cases = [
+            (number(7, 0), number(8, 0), number(56, 0)),
+            (
+                number(1_414_213_562_373_095, -15),
+                number(1_414_213_562_373_095, -15),
+                number(2_000_000_000_000_000, -15),
+            ),
+            (
+                number(-1_414_213_562_373_095, -15),
+                number(1_414_213_562_373_095, -15),
+                number(-2_000_000_000_000_000, -15),
+            ),
+            (
+                number(-1_414_213_562_373_095, -15),
+                number(-1_414_213_562_373_095, -15),
+                number(2_000_000_000_000_000, -15),
+            ),
+            (
+                number(3_214_285_714_285_706, -15),
+                number(3_111_111_111_111_119, -15),
+                number(1_000_000_000_000_000, -14),
+            ),
+            (
+                number(9_999_999_999_999_999, 0),
+                number(9_999_999_999_999_999, 0),
+                number(9_999_999_999_999_998, 16),
+            ),
+        ];
+        for (x, y, z) in mul_cases {
+            assert_eq!(x.checked_mul(&y), Ok(z), "{} * {}", x, y);
+        }
+        let div_cases = [
+            (number(1, 0), number(2, 0), number(5, -1)),
+            (number(1, 0), number(10, 0), number(1, -1)),
+            (number(1, 0), number(-10, 0), number(-1, -1)),
+            (zero(), number(100, 0), zero()),
+            (
+                number(1_414_213_562_373_095, -10),
+                number(1_414_213_562_373_095, -10),
+                number(1, 0),
+            ),
+            (
+                number(9_999_999_999_999_999, 0),
+                number(1_000_000_000_000_000, 0),
+                number(9_999_999_999_999_999, -15),
+            ),
+            (
+                number(2, 0),
+                number(3, 0),
+                number(6_666_666_666_666_667, -16),
+            ),
+            (
+                number(-2, 0),
+                number(3, 0),
+                number(-6_666_666_666_666_667, -16),
+            ),
+        ];
+        for (x, y, z) in div_cases {
+            assert_eq!(x.checked_div(&y), Ok(z), "{} / {}", x, y);
+        }
+
+        // results near the exponent bounds are encoded as rippled
+        for (result, payload) in [
+            (
+                max.checked_div(&value("10")),
+                ISSUED_MASK | POSITIVE_MASK | ((79 + 97) << 54) | 9_999_999_999_999_999,
+            ),
+            (
+                value("1e40").checked_mul(&value("1e20")),
+                ISSUED_MASK | POSITIVE_MASK | ((45 + 97) << 54) | 1_000_000_000_000_000,
+            ),
+            (
+                (-max.clone()).checked_add(&number(1_000_000_000_000_000, 80)),
+                ISSUED_MASK | ((80 + 97) << 54) | 8_999_999_999_999_999,
+            ),
+            (
+                value("1e-50").checked_mul(&value("1e-31")),
+                ISSUED_MASK | POSITIVE_MASK | (1 << 54) | 1_000_000_000_000_000,
+            ),
+        ] {
+            assert_eq!(
+                result.unwrap().binary_serialize(true),
+                Ok(payload.to_be_bytes().to_vec())
+            );
+        }
+
+        // out of the issued value range
+        assert!(matches!(max.checked_add(&max), Err(Error::OutOfRange(_))));
+        assert!(matches!(
+            (-max.clone()).checked_sub(&max),
+            Err(Error::OutOfRange(_))
+        ));
+        assert!(matches!(
+            max.checked_mul(&value("10")),
+            Err(Error::OutOfRange(_))
+        ));
+        assert!(matches!(
+            max.checked_div(&value("0.1")),
+            Err(Error::OutOfRange(_))
+        ));
+        assert_eq!(value("1e-50").checked_mul(&value("1e-50")), Ok(zero()));
+        assert_eq!(value("1e-80").checked_div(&value("1e10")), Ok(zero()));
+        assert!(matches!(
+            value("1").checked_div(&zero()),
+            Err(Error::InvalidData(_))
+        ));
+
+        assert_eq!(-value("3.14"), value("-3.14"));
+        assert_eq!(-IssuedValueType::zero(), IssuedValueType::zero());
+        assert!(IssuedValueType::zero().is_zero());
+        assert!(!value("1e-81").is_zero());
+    }
+
+    #[test]
+    fn issued_value_ordering() {
+        let mut values: Vec<IssuedValueType> =
+            ["1e20", "-1", "0.5", "0", "-2", "1", "-0.001", "1e-81"]
+                .iter()
+                .map(|v| v.parse().unwrap())
+                .collect();
+        values.sort();
+        let sorted: Vec<_> = values.iter().map(|v| v.to_string()).collect();
+        assert_eq!(
+            sorted,
+            ["-2", "-1", "-0.001", "0", "1e-81", "0.5", "1", "1e20"]
+        );
+        let (a, b): (IssuedValueType, IssuedValueType) =
+            ("-0.5".parse().unwrap(), "-0.4".parse().unwrap());
+        assert!(a < b);
+        assert_eq!(a.max(b.clone()), b);
+        assert!("1e-96".parse::<IssuedValueType>().is_err());
+    }
 }
@@ -1,7 +1,21 @@
 use ripemd::{Digest as _, Ripemd160};
 use sha2::Sha256;
 
-use crate::{traits::BinarySerialize, Vec};
+use crate::{public_key::PublicKey, traits::BinarySerialize, Error, Vec};
+
+/// Hash prefix of a transaction ID i.e. "TXN\0"
+/// https://xrpl.org/docs/references/protocol/data-types/basic-data-types#hash-prefixes
+const TRANSACTION_ID_PREFIX: [u8; 4] = [0x54, 0x58, 0x4e, 0x00];
+/// Hash prefix of a single signature i.e. "STX\0"
+const SIGNING_PREFIX: [u8; 4] = [0x53, 0x54, 0x58, 0x00];
+/// Hash prefix of a multi-signature i.e. "SMT\0"
+const MULTI_SIGNING_PREFIX: [u8; 4] = [0x53, 0x4d, 0x54, 0x00];
+
+/// SHA-512Half i.e. the first 32 bytes of the SHA-512 digest of `data`
+pub(crate) fn sha512_half(data: &[u8]) -> [u8; 32] {
+    let digest: [u8; 64] = sha2::Sha512::digest(data).into();
+    digest[..32].try_into().expect("it is a 32 byte digest")
+}
 
 /// Convert a 33 byte Secp256k1 pub key to an XRPL account ID
 ///
@@ -13,42 +27,172 @@ pub fn secp256k1_public_key_to_account_id(public_key: [u8; 33]) -> [u8; 20] {
     Ripemd160::digest(pubkey_inner_hash).into()
 }
 
+/// Convert a secp256k1 or ed25519 pub key to an XRPL account ID
+pub fn public_key_to_account_id(public_key: PublicKey) -> [u8; 20] {
+    secp256k1_public_key_to_account_id(public_key.to_bytes())
+}
+
+/// Calculate the tx digest ready for (single) signing e.g. by the master or regular key
+///
+/// `tx` an XRPL tx type
+///
+/// Returns the tx digest ready for signing
+pub fn digest_for_signing(tx: &impl BinarySerialize) -> Result<[u8; 32], Error> {
+    let tx_data = encode_for_signing(tx)?;
+    Ok(sha512_half(&tx_data))
+}
+
+/// Encode a tx ready for (single) signing
+pub fn encode_for_signing(tx: &impl BinarySerialize) -> Result<Vec<u8>, Error> {
+    Ok([
+        SIGNING_PREFIX.as_slice(),
+        tx.binary_serialize(true)?.as_slice(),
+    ]
+    .concat())
+}
+
+/// Prepare a pre-encoded tx for (single) signing
+///
+/// `tx_data` RBC encoded tx data (in 'for signing' mode)
+///
+/// Returns the tx digest ready for signing
+pub fn digest_for_signing_pre(tx_data: &[u8]) -> [u8; 32] {
+    sha512_half(&[SIGNING_PREFIX.as_slice(), tx_data].concat())
+}
+
 /// Calculate the tx digest ready for multi signing
 ///
 /// `tx` an XRPL tx type
-/// `public_key` the secp256k1 public key that will sign the digest
+/// `public_key` the public key that will sign the digest
 ///
 /// Returns the tx digest ready for signing
-pub fn digest_for_multi_signing(tx: &impl BinarySerialize, public_key: [u8; 33]) -> [u8; 32] {
-    let tx_data = encode_for_multi_signing(tx, public_key);
-    let digest: [u8; 64] = sha2::Sha512::digest(tx_data).into();
-    digest[..32].try_into().expect("it is a 32 byte digest")
+pub fn digest_for_multi_signing(
+    tx: &impl BinarySerialize,
+    public_key: PublicKey,
+) -> Result<[u8; 32], Error> {
+    let tx_data = encode_for_multi_signing(tx, public_key)?;
+    Ok(sha512_half(&tx_data))
 }
 
 /// Encode a tx ready for multi-signing
-pub fn encode_for_multi_signing(tx: &impl BinarySerialize, public_key: [u8; 33]) -> Vec<u8> {
-    [
-        &[0x53, 0x4d, 0x54, 0x00],
-        tx.binary_serialize(true).as_slice(),
-        secp256k1_public_key_to_account_id(public_key).as_slice(),
+pub fn encode_for_multi_signing(
+    tx: &impl BinarySerialize,
+    public_key: PublicKey,
+) -> Result<Vec<u8>, Error> {
+    Ok([
+        MULTI_SIGNING_PREFIX.as_slice(),
+        tx.binary_serialize(true)?.as_slice(),
+        public_key.account_id().as_slice(),
     ]
     .concat()
-    .to_vec()
+    .to_vec())
 }
 
 /// Prepare a pre-encoded tx for multi-signing by some `public_key`
 ///
 /// `tx_data` RBC encoded tx data (in 'for signing' mode)
-/// `public_key` the secp256k1 public key that will sign the digest
+/// `public_key` the public key that will sign the digest
 ///
 /// Returns the tx digest ready for signing
-pub fn digest_for_multi_signing_pre(tx_data: &[u8], public_key: [u8; 33]) -> [u8; 32] {
+pub fn digest_for_multi_signing_pre(tx_data: &[u8], public_key: PublicKey) -> [u8; 32] {
     let tx_data = [
-        &[0x53, 0x4d, 0x54, 0x00],
+        MULTI_SIGNING_PREFIX.as_slice(),
         tx_data,
-        secp256k1_public_key_to_account_id(public_key).as_slice(),
+        public_key.account_id().as_slice(),
     ]
     .concat();
-    let digest: [u8; 64] = sha2::Sha512::digest(tx_data).into();
-    digest[..32].try_into().expect("it is a 32 byte digest")
+    sha512_half(&tx_data)
+}
+
+/// Calculate the transaction hash (ID) of a signed tx i.e. as it will be identified in the ledger
+///
+/// `tx` an XRPL tx type with its signature(s) attached
+///
+/// Returns the tx hash
+pub fn transaction_hash(tx: &impl BinarySerialize) -> Result<[u8; 32], Error> {
+    Ok(transaction_hash_pre(&tx.binary_serialize(false)?))
+}
+
+/// Calculate the transaction hash (ID) of a pre-encoded signed tx
+///
+/// `tx_data` RBC encoded tx data (in 'non-signing' mode i.e. including signature(s))
+///
+/// Returns the tx hash
+pub fn transaction_hash_pre(tx_data: &[u8]) -> [u8; 32] {
+    sha512_half(&[TRANSACTION_ID_PREFIX.as_slice(), tx_data].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{signature::DerSignature, transaction::Payment};
+
+    #[test]
+    fn digest_for_signing_payment() {
+        let mut payment = Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000,
+            1,
+            0,
+            12,
+            1,
+            Some(PublicKey::try_from([3_u8; 33]).unwrap()),
+        );
+        let encoded = encode_for_signing(&payment).unwrap();
+        assert_eq!(encoded[..4], *b"STX\0");
+        assert_eq!(encoded[4..], payment.binary_serialize(true).unwrap());
+
+        let digest = digest_for_signing(&payment).unwrap();
+        assert_eq!(
+            digest,
+            digest_for_signing_pre(&payment.binary_serialize(true).unwrap())
+        );
+        assert_eq!(
+            hex::encode(digest),
+            "cd7cab5303bd2f8e71672c840cfa7121e3078ed38187c99c99addcc343e1c04b"
+        );
+        // the signature is not signed
+        payment
+            .attach_signature(DerSignature::try_from([4_u8; 65]).unwrap())
+            .unwrap();
+        assert_eq!(digest_for_signing(&payment).unwrap(), digest);
+        // single and multi-signing digests differ
+        assert_ne!(
+            digest_for_multi_signing(&payment, PublicKey::try_from([3_u8; 33]).unwrap()).unwrap(),
+            digest
+        );
+    }
+    #[test]
+    fn transaction_hash_signed_payment() {
+        let mut payment = Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000,
+            1,
+            0,
+            12,
+            1,
+            Some(PublicKey::try_from([3_u8; 33]).unwrap()),
+        );
+        payment
+            .attach_signature(DerSignature::try_from([4_u8; 65]).unwrap())
+            .unwrap();
+
+        let hash = transaction_hash(&payment).unwrap();
+        assert_eq!(
+            hash,
+            transaction_hash_pre(&payment.binary_serialize(false).unwrap())
+        );
+        // as xrpl.js `hashes.hashSignedTx` (see 'tests/transaction_decoding.rs')
+        assert_eq!(
+            hex::encode(hash),
+            "b53a3c2d748e72a9b9694c0912a4894970858f4dac782d5376cc2c3602b834e6"
+        );
+        // the signature is part of the ID
+        payment
+            .attach_signature(DerSignature::try_from([5_u8; 65]).unwrap())
+            .unwrap();
+        assert_ne!(transaction_hash(&payment).unwrap(), hash);
+    }
 }
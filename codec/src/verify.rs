@@ -0,0 +1,256 @@
+//! Signature verification for single and multi-signed txs
+//!
+//! secp256k1 signatures are made over the SHA-512Half tx digest while ed25519 signatures are made over the raw
+//! (prefixed) signing serialization
+use alloc::string::ToString;
+
+#[cfg(feature = "secp256k1")]
+use crate::utils::sha512_half;
+use crate::{
+    public_key::PublicKey,
+    traits::BinarySerialize,
+    utils::{encode_for_multi_signing, encode_for_signing},
+    Error,
+};
+
+/// Verify a secp256k1 `signature` of `digest` (e.g. from `digest_for_signing` or `digest_for_multi_signing`)
+///
+/// `signature` may be DER encoded or compact i.e. (r || s) with an optional trailing recovery ID
+///
+/// Signatures with a high S value are not canonical and rejected
+#[cfg(feature = "secp256k1")]
+pub fn verify_secp256k1(
+    public_key: [u8; 33],
+    digest: [u8; 32],
+    signature: &[u8],
+) -> Result<(), Error> {
+    use k256::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+
+    let verifying_key = VerifyingKey::from_sec1_bytes(&public_key)
+        .map_err(|_| Error::InvalidData("invalid secp256k1 public key".to_string()))?;
+    let signature = match signature.len() {
+        64 | 65 => Signature::from_slice(&signature[..64]),
+        _ => Signature::from_der(signature),
+    }
+    .map_err(|_| Error::InvalidData("malformed secp256k1 signature".to_string()))?;
+    if signature.normalize_s().is_some() {
+        return Err(Error::InvalidData(
+            "non-canonical secp256k1 signature".to_string(),
+        ));
+    }
+
+    verifying_key
+        .verify_prehash(&digest, &signature)
+        .map_err(|_| Error::InvalidData("invalid signature".to_string()))
+}
+
+/// Verify an ed25519 `signature` of `message` (e.g. from `encode_for_signing` or `encode_for_multi_signing`)
+///
+/// `public_key` is the (unprefixed) ed25519 public key
+#[cfg(feature = "ed25519")]
+pub fn verify_ed25519(public_key: [u8; 32], message: &[u8], signature: &[u8]) -> Result<(), Error> {
+    use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+    let verifying_key = VerifyingKey::from_bytes(&public_key)
+        .map_err(|_| Error::InvalidData("invalid ed25519 public key".to_string()))?;
+    let signature = Signature::from_slice(signature)
+        .map_err(|_| Error::InvalidData("malformed ed25519 signature".to_string()))?;
+
+    verifying_key
+        .verify(message, &signature)
+        .map_err(|_| Error::InvalidData("invalid signature".to_string()))
+}
+
+/// Verify a (single) `signature` of `tx` by `public_key`
+///
+/// The signing algorithm is given by the type of `public_key`
+pub fn verify_signature(
+    tx: &impl BinarySerialize,
+    public_key: PublicKey,
+    signature: &[u8],
+) -> Result<(), Error> {
+    verify(&encode_for_signing(tx)?, public_key, signature)
+}
+
+/// Verify a multi-signature of `tx` by `public_key`
+///
+/// The signing algorithm is given by the type of `public_key`
+pub fn verify_multi_signature(
+    tx: &impl BinarySerialize,
+    public_key: PublicKey,
+    signature: &[u8],
+) -> Result<(), Error> {
+    verify(
+        &encode_for_multi_signing(tx, public_key)?,
+        public_key,
+        signature,
+    )
+}
+
+/// Verify `signature` of the prefixed signing data `message` by `public_key`
+#[allow(unused_variables)]
+fn verify(message: &[u8], public_key: PublicKey, signature: &[u8]) -> Result<(), Error> {
+    match public_key {
+        #[cfg(feature = "secp256k1")]
+        PublicKey::Secp256k1(public_key) => {
+            verify_secp256k1(public_key.into(), sha512_half(message), signature)
+        }
+        #[cfg(feature = "ed25519")]
+        PublicKey::Ed25519(public_key) => verify_ed25519(public_key, message, signature),
+        #[allow(unreachable_patterns)]
+        _ => Err(Error::InvalidData(
+            "verification of this key type is not enabled".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::Payment;
+
+    fn payment(signing_pub_key: Option<PublicKey>) -> Payment {
+        Payment::new(
+            [1_u8; 20],
+            [2_u8; 20],
+            5_000_000_u64,
+            1_u32,
+            1_u32,
+            1_000,
+            38_887_387_u32,
+            signing_pub_key,
+        )
+    }
+
+    #[cfg(feature = "secp256k1")]
+    #[test]
+    fn verify_secp256k1_signatures() {
+        use crate::{
+            signature::DerSignature,
+            utils::{digest_for_multi_signing, digest_for_signing},
+        };
+        use k256::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+
+        let signing_key = SigningKey::from_slice(&[7_u8; 32]).unwrap();
+        let public_key: [u8; 33] = signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            .try_into()
+            .unwrap();
+        let tx = payment(Some(PublicKey::try_from(public_key).unwrap()));
+        let digest = digest_for_signing(&tx).unwrap();
+        let (signature, recovery_id): (Signature, _) =
+            signing_key.sign_prehash_recoverable(&digest).unwrap();
+
+        // DER, compact and compact with recovery ID
+        let der = signature.to_der();
+        assert_eq!(
+            verify_signature(
+                &tx,
+                PublicKey::try_from(public_key).unwrap(),
+                der.as_bytes()
+            ),
+            Ok(())
+        );
+        let compact = signature.to_bytes();
+        assert_eq!(
+            DerSignature::from_compact(&compact).unwrap().as_bytes(),
+            der.as_bytes()
+        );
+        assert_eq!(verify_secp256k1(public_key, digest, &compact), Ok(()));
+        let recoverable = [compact.as_slice(), &[recovery_id.to_byte()]].concat();
+        assert_eq!(
+            verify_signature(&tx, PublicKey::try_from(public_key).unwrap(), &recoverable),
+            Ok(())
+        );
+
+        // wrong tx
+        assert_eq!(
+            verify_signature(
+                &payment(None),
+                PublicKey::try_from(public_key).unwrap(),
+                &compact
+            ),
+            Err(Error::InvalidData("invalid signature".to_string()))
+        );
+        // high S
+        let (r, s) = signature.split_scalars();
+        let high_s = Signature::from_scalars(r, -*s).unwrap();
+        assert_eq!(
+            verify_secp256k1(public_key, digest, &high_s.to_bytes()),
+            Err(Error::InvalidData(
+                "non-canonical secp256k1 signature".to_string()
+            ))
+        );
+        // malformed
+        assert!(verify_secp256k1(public_key, digest, &[1_u8; 70]).is_err());
+
+        // multi-signed
+        let tx = payment(None);
+        let digest =
+            digest_for_multi_signing(&tx, PublicKey::try_from(public_key).unwrap()).unwrap();
+        let signature: Signature = signing_key.sign_prehash(&digest).unwrap();
+        assert_eq!(
+            verify_multi_signature(
+                &tx,
+                PublicKey::try_from(public_key).unwrap(),
+                signature.to_der().as_bytes()
+            ),
+            Ok(())
+        );
+        assert!(verify_signature(
+            &tx,
+            PublicKey::try_from(public_key).unwrap(),
+            signature.to_der().as_bytes()
+        )
+        .is_err());
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn verify_ed25519_signatures() {
+        use crate::{field::FieldId, signature, traits::BinaryDeserialize, types::*};
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let signing_key = SigningKey::from_bytes(&[7_u8; 32]);
+        let public_key = PublicKey::Ed25519(signing_key.verifying_key().to_bytes());
+        let tx = payment(Some(public_key));
+
+        // signs the signing serialization, not its digest
+        let signature = signing_key.sign(&encode_for_signing(&tx).unwrap());
+        assert_eq!(
+            verify_signature(&tx, public_key, &signature.to_bytes()),
+            Ok(())
+        );
+        let signature = signing_key.sign(&crate::utils::digest_for_signing(&tx).unwrap());
+        assert!(verify_signature(&tx, public_key, &signature.to_bytes()).is_err());
+
+        // single-signed end to end
+        let mut tx = payment(Some(public_key));
+        let signature = signing_key.sign(&encode_for_signing(&tx).unwrap());
+        tx.attach_signature(signature::Signature::Ed25519(signature.to_bytes()))
+            .unwrap();
+        let object = STObject::binary_deserialize(&tx.binary_serialize(false).unwrap()).unwrap();
+        let txn_signature = match object.get(&FieldId::new(BLOB_TYPE_CODE, 4)) {
+            Some(SerializedValue::Blob(BlobType(txn_signature))) => txn_signature.clone(),
+            other => panic!("unexpected TxnSignature: {:?}", other),
+        };
+        assert_eq!(txn_signature, signature.to_bytes());
+        assert_eq!(verify_signature(&tx, public_key, &txn_signature), Ok(()));
+
+        // multi-signed
+        let tx = payment(None);
+        let signature = signing_key.sign(&encode_for_multi_signing(&tx, public_key).unwrap());
+        assert_eq!(
+            verify_multi_signature(&tx, public_key, &signature.to_bytes()),
+            Ok(())
+        );
+        assert!(verify_ed25519(
+            signing_key.verifying_key().to_bytes(),
+            b"other",
+            &signature.to_bytes()
+        )
+        .is_err());
+    }
+}
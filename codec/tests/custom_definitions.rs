@@ -0,0 +1,90 @@
+#![cfg(test)]
+//! A downstream crate compiling fields and transactions against its own network's definitions
+
+extern crate alloc;
+
+use xrpl_codec::{
+    definitions::FieldDefinition,
+    field::{
+        decode_field_value, Account, Fee, FieldId, Sequence, SigningPubKey, TransactionType,
+        TxnSignature,
+    },
+    traits::{BinaryDeserialize, BinarySerialize, CodecField, CodecToFields},
+    types::{AccountIdType, AmountType, BlobType, UInt16Type, UInt32Type},
+    Error, Vec,
+};
+use xrpl_codec_utils::{field_definitions, transaction_type_codes, Field, Transaction};
+
+transaction_type_codes!("tests/res/custom_definitions.json");
+
+const CUSTOM_FIELDS: &[FieldDefinition] = &field_definitions!("tests/res/custom_definitions.json");
+
+#[derive(Field, Debug, Clone, PartialEq, Eq)]
+#[definitions("tests/res/custom_definitions.json")]
+pub struct InvokeData(pub BlobType);
+
+#[derive(Transaction, Debug, Clone, PartialEq, Eq)]
+#[transaction_type(Invoke)]
+#[definitions("tests/res/custom_definitions.json")]
+pub struct Invoke {
+    pub account: Account,
+    pub transaction_type: TransactionType,
+    pub fee: Fee,
+    pub sequence: Sequence,
+    pub signing_pub_key: SigningPubKey,
+    pub invoke_data: InvokeData,
+    pub txn_signature: TxnSignature,
+}
+
+#[test]
+fn custom_transaction_type() {
+    assert_eq!(TransactionTypeCode::Invoke.code(), 99);
+    assert_eq!(
+        TransactionTypeCode::from_code(99),
+        Some(TransactionTypeCode::Invoke)
+    );
+    // not in the custom definitions
+    assert_eq!(TransactionTypeCode::from_code(12), None);
+}
+
+#[test]
+fn custom_field() {
+    let invoke_data = InvokeData(BlobType(vec![1, 2, 3]));
+    assert_eq!(invoke_data.field_id(), FieldId::new(7, 99));
+    let buf = invoke_data.binary_serialize(true).unwrap();
+    assert_eq!(buf, [0x70, 99, 3, 1, 2, 3]);
+    assert_eq!(InvokeData::binary_deserialize(&buf), Ok(invoke_data));
+
+    let definition = CUSTOM_FIELDS
+        .iter()
+        .find(|f| f.name == "InvokeData")
+        .unwrap();
+    assert_eq!(definition.field_id(), FieldId::new(7, 99));
+    assert!(definition.is_vl_encoded);
+}
+
+#[test]
+fn custom_transaction_round_trip() {
+    let invoke = Invoke {
+        account: Account(AccountIdType([1_u8; 20])),
+        transaction_type: TransactionType(UInt16Type(TransactionTypeCode::Invoke.code())),
+        fee: Fee(AmountType::Drops(10)),
+        sequence: Sequence(UInt32Type(1)),
+        signing_pub_key: SigningPubKey(BlobType(vec![2_u8; 33])),
+        invoke_data: InvokeData(BlobType(vec![3_u8; 4])),
+        txn_signature: TxnSignature(BlobType(vec![4_u8; 65])),
+    };
+
+    let buf = invoke.binary_serialize(false).unwrap();
+    assert_eq!(Invoke::decode(&buf), Ok(invoke.clone()));
+
+    // the signing encoding has no signature
+    let buf = invoke.binary_serialize(true).unwrap();
+    assert_eq!(
+        Invoke::decode(&buf),
+        Ok(Invoke {
+            txn_signature: TxnSignature::default(),
+            ..invoke
+        })
+    );
+}
@@ -1,9 +1,10 @@
 #![cfg(test)]
 
-use std::ops::Mul;
 use std::process::Command;
 
 use xrpl_codec::field::Amount;
+use xrpl_codec::public_key::PublicKey;
+use xrpl_codec::signature::DerSignature;
 use xrpl_codec::transaction::{NFTokenAcceptOffer, NFTokenCreateOffer, PaymentAltCurrency, PaymentAltCurrencyWithDestinationTag, PaymentWithDestinationTag};
 use xrpl_codec::types::{
     AccountIdType, AmountType, CurrencyCodeType, IssuedAmountType, IssuedValueType,
@@ -11,6 +12,7 @@ use xrpl_codec::types::{
 use xrpl_codec::{
     traits::BinarySerialize,
     transaction::{Payment, SignerListSet},
+    utils::transaction_hash,
 };
 
 // Assert `encoded` input decodes to `expected` JSON format (whitespace will be removed)
@@ -37,6 +39,26 @@ fn assert_decodes(encoded: &[u8], expected: &str) {
     );
 }
 
+// Assert the signed tx `encoded` has `hash` as given by xrpl.js
+fn assert_hash(encoded: &[u8], hash: [u8; 32]) {
+    let js_test = format!(
+        "const xrpl = require(\"xrpl\"); console.log(xrpl.hashes.hashSignedTx('{}'));",
+        hex::encode_upper(encoded)
+    );
+    let result = Command::new("node")
+        .env("NODE_PATH", "./tests/node_modules")
+        .arg(format!("--eval={}", js_test))
+        .output()
+        .expect("node command failed to start");
+
+    assert_eq!(
+        core::str::from_utf8(&result.stdout)
+            .expect("valid utf8 only")
+            .trim(),
+        hex::encode_upper(hash)
+    );
+}
+
 #[test]
 fn serialize_payment_tx() {
     let account = [1_u8; 20];
@@ -45,7 +67,7 @@ fn serialize_payment_tx() {
     let nonce = 1_u32;
     let ticket_number = 1_u32;
     let fee = 1_000; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
     let mut payment = Payment::new(
         account,
@@ -55,7 +77,7 @@ fn serialize_payment_tx() {
         ticket_number,
         fee,
         source_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_payment_json = r"{
@@ -71,11 +93,11 @@ fn serialize_payment_tx() {
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
 
     }";
-    let encoded_no_signature = payment.binary_serialize(true);
+    let encoded_no_signature = payment.binary_serialize(true).unwrap();
     assert_decodes(encoded_no_signature.as_slice(), expected_payment_json);
 
     // with signature
-    payment.attach_signature([7_u8; 65]);
+    payment.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_payment_json = r"{
         TransactionType: 'Payment',
         Flags: 2147483648,
@@ -85,11 +107,11 @@ fn serialize_payment_tx() {
         Amount: '5000000',
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_with_signature = payment.binary_serialize(false);
+    let encoded_with_signature = payment.binary_serialize(false).unwrap();
     assert_decodes(encoded_with_signature.as_slice(), expected_payment_json);
 }
 
@@ -111,7 +133,7 @@ fn serialize_payment_alt_tx() {
     let nonce = 1_u32;
     let ticket_number = 1_u32;
     let fee = 1_000; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
     let mut payment = PaymentAltCurrency::new(
         account,
@@ -121,7 +143,7 @@ fn serialize_payment_alt_tx() {
         ticket_number,
         fee,
         source_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_payment_json = r"{
@@ -141,12 +163,12 @@ fn serialize_payment_alt_tx() {
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
 
     }";
-    let encoded_no_signature = payment.binary_serialize(true);
+    let encoded_no_signature = payment.binary_serialize(true).unwrap();
 
     assert_decodes(encoded_no_signature.as_slice(), expected_payment_json);
 
     // with signature
-    payment.attach_signature([7_u8; 65]);
+    payment.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_payment_json = r"{
         TransactionType: 'Payment',
         Flags: 2147483648,
@@ -160,11 +182,11 @@ fn serialize_payment_alt_tx() {
         },
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_with_signature = payment.binary_serialize(false);
+    let encoded_with_signature = payment.binary_serialize(false).unwrap();
     assert_decodes(encoded_with_signature.as_slice(), expected_payment_json);
 }
 
@@ -175,10 +197,9 @@ fn serialize_payment_alt_tx_decimal_amount() {
     // AST token info
     let issuer = [3_u8; 20];
     let token_symbol = b"AST";
-    let token_amount = 3.14; // 3.14 AST
+    let token_amount = "3.14"; // 3.14 AST
     let issued_amount = IssuedAmountType::from_issued_value(
-        IssuedValueType::from_mantissa_exponent(token_amount.mul(100_f64).round() as i64, -2)
-            .unwrap(),
+        token_amount.parse::<IssuedValueType>().unwrap(),
         CurrencyCodeType::Standard(token_symbol.clone()),
         AccountIdType(issuer),
     )
@@ -187,7 +208,7 @@ fn serialize_payment_alt_tx_decimal_amount() {
     let nonce = 1_u32;
     let ticket_number = 1_u32;
     let fee = 1_000; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
     let mut payment = PaymentAltCurrency::new(
         account,
@@ -197,7 +218,7 @@ fn serialize_payment_alt_tx_decimal_amount() {
         ticket_number,
         fee,
         source_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_payment_json = r"{
@@ -217,12 +238,12 @@ fn serialize_payment_alt_tx_decimal_amount() {
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
 
     }";
-    let encoded_no_signature = payment.binary_serialize(true);
+    let encoded_no_signature = payment.binary_serialize(true).unwrap();
 
     assert_decodes(encoded_no_signature.as_slice(), expected_payment_json);
 
     // with signature
-    payment.attach_signature([7_u8; 65]);
+    payment.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_payment_json = r"{
         TransactionType: 'Payment',
         Flags: 2147483648,
@@ -236,11 +257,11 @@ fn serialize_payment_alt_tx_decimal_amount() {
         },
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_with_signature = payment.binary_serialize(false);
+    let encoded_with_signature = payment.binary_serialize(false).unwrap();
     assert_decodes(encoded_with_signature.as_slice(), expected_payment_json);
 }
 
@@ -251,10 +272,9 @@ fn serialize_payment_alt_tx_non_standard_currency_code() {
     // AST token info
     let issuer = [3_u8; 20];
     let token_symbol = [5_u8; 20];
-    let token_amount = 3.14; // 3.14 AST
+    let token_amount = "3.14"; // 3.14 AST
     let issued_amount = IssuedAmountType::from_issued_value(
-        IssuedValueType::from_mantissa_exponent(token_amount.mul(100_f64).round() as i64, -2)
-            .unwrap(),
+        token_amount.parse::<IssuedValueType>().unwrap(),
         CurrencyCodeType::NonStandard(token_symbol),
         AccountIdType(issuer),
     )
@@ -263,7 +283,7 @@ fn serialize_payment_alt_tx_non_standard_currency_code() {
     let nonce = 1_u32;
     let ticket_number = 1_u32;
     let fee = 1_000; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
     let mut payment = PaymentAltCurrency::new(
         account,
@@ -273,7 +293,7 @@ fn serialize_payment_alt_tx_non_standard_currency_code() {
         ticket_number,
         fee,
         source_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_payment_json = r"{
@@ -293,12 +313,12 @@ fn serialize_payment_alt_tx_non_standard_currency_code() {
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
 
     }";
-    let encoded_no_signature = payment.binary_serialize(true);
+    let encoded_no_signature = payment.binary_serialize(true).unwrap();
 
     assert_decodes(encoded_no_signature.as_slice(), expected_payment_json);
 
     // with signature
-    payment.attach_signature([7_u8; 65]);
+    payment.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_payment_json = r"{
         TransactionType: 'Payment',
         Flags: 2147483648,
@@ -312,11 +332,11 @@ fn serialize_payment_alt_tx_non_standard_currency_code() {
         },
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_with_signature = payment.binary_serialize(false);
+    let encoded_with_signature = payment.binary_serialize(false).unwrap();
     assert_decodes(encoded_with_signature.as_slice(), expected_payment_json);
 }
 
@@ -339,7 +359,7 @@ fn serialize_payment_alt_destination_tag_tx() {
     let nonce = 1_u32;
     let ticket_number = 1_u32;
     let fee = 1_000; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
     let destination_tag = 12_124_121_u32;
     let mut payment = PaymentAltCurrencyWithDestinationTag::new(
@@ -351,7 +371,7 @@ fn serialize_payment_alt_destination_tag_tx() {
         fee,
         source_tag,
         destination_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_payment_json = r"{
@@ -371,12 +391,12 @@ fn serialize_payment_alt_destination_tag_tx() {
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_no_signature = payment.binary_serialize(true);
+    let encoded_no_signature = payment.binary_serialize(true).unwrap();
 
     assert_decodes(encoded_no_signature.as_slice(), expected_payment_json);
 
     // with signature
-    payment.attach_signature([7_u8; 65]);
+    payment.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_payment_json = r"{
         TransactionType: 'Payment',
         Flags: 2147483648,
@@ -391,11 +411,11 @@ fn serialize_payment_alt_destination_tag_tx() {
         },
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_with_signature = payment.binary_serialize(false);
+    let encoded_with_signature = payment.binary_serialize(false).unwrap();
     assert_decodes(encoded_with_signature.as_slice(), expected_payment_json);
 }
 
@@ -418,7 +438,7 @@ fn serialize_payment_alt_destination_tag_as_zero_tx() {
     let nonce = 1_u32;
     let ticket_number = 1_u32;
     let fee = 1_000; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
     let destination_tag = 0_u32;
     let mut payment = PaymentAltCurrencyWithDestinationTag::new(
@@ -430,7 +450,7 @@ fn serialize_payment_alt_destination_tag_as_zero_tx() {
         fee,
         source_tag,
         destination_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_payment_json = r"{
@@ -450,12 +470,12 @@ fn serialize_payment_alt_destination_tag_as_zero_tx() {
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_no_signature = payment.binary_serialize(true);
+    let encoded_no_signature = payment.binary_serialize(true).unwrap();
 
     assert_decodes(encoded_no_signature.as_slice(), expected_payment_json);
 
     // with signature
-    payment.attach_signature([7_u8; 65]);
+    payment.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_payment_json = r"{
         TransactionType: 'Payment',
         Flags: 2147483648,
@@ -470,11 +490,11 @@ fn serialize_payment_alt_destination_tag_as_zero_tx() {
         },
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_with_signature = payment.binary_serialize(false);
+    let encoded_with_signature = payment.binary_serialize(false).unwrap();
     assert_decodes(encoded_with_signature.as_slice(), expected_payment_json);
 }
 
@@ -486,7 +506,7 @@ fn serialize_payment_with_destination_tag_tx() {
     let nonce = 1_u32;
     let ticket_number = 1_u32;
     let fee = 1_000; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
     let destination_tag = 12_124_121_u32;
     let mut payment = PaymentWithDestinationTag::new(
@@ -498,7 +518,7 @@ fn serialize_payment_with_destination_tag_tx() {
         fee,
         source_tag,
         destination_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_payment_json = r"{
@@ -515,12 +535,12 @@ fn serialize_payment_with_destination_tag_tx() {
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
 
     }";
-    let encoded_no_signature = payment.binary_serialize(true);
+    let encoded_no_signature = payment.binary_serialize(true).unwrap();
 
     assert_decodes(encoded_no_signature.as_slice(), expected_payment_json);
 
     // with signature
-    payment.attach_signature([7_u8; 65]);
+    payment.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_payment_json = r"{
         TransactionType: 'Payment',
         Flags: 2147483648,
@@ -531,11 +551,11 @@ fn serialize_payment_with_destination_tag_tx() {
         Amount: '5000000',
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_with_signature = payment.binary_serialize(false);
+    let encoded_with_signature = payment.binary_serialize(false).unwrap();
     assert_decodes(encoded_with_signature.as_slice(), expected_payment_json);
 }
 
@@ -547,7 +567,7 @@ fn serialize_payment_with_destination_tag_as_zero_tx() {
     let nonce = 1_u32;
     let ticket_number = 1_u32;
     let fee = 1_000; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
     let destination_tag = 0_u32;
     let mut payment = PaymentWithDestinationTag::new(
@@ -559,7 +579,7 @@ fn serialize_payment_with_destination_tag_as_zero_tx() {
         fee,
         source_tag,
         destination_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_payment_json = r"{
@@ -576,12 +596,12 @@ fn serialize_payment_with_destination_tag_as_zero_tx() {
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
 
     }";
-    let encoded_no_signature = payment.binary_serialize(true);
+    let encoded_no_signature = payment.binary_serialize(true).unwrap();
 
     assert_decodes(encoded_no_signature.as_slice(), expected_payment_json);
 
     // with signature
-    payment.attach_signature([7_u8; 65]);
+    payment.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_payment_json = r"{
         TransactionType: 'Payment',
         Flags: 2147483648,
@@ -592,11 +612,11 @@ fn serialize_payment_with_destination_tag_as_zero_tx() {
         Amount: '5000000',
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_with_signature = payment.binary_serialize(false);
+    let encoded_with_signature = payment.binary_serialize(false).unwrap();
     assert_decodes(encoded_with_signature.as_slice(), expected_payment_json);
 }
 
@@ -608,7 +628,7 @@ fn serialize_payment_zero_values() {
     let nonce = 0_u32;
     let ticket_number = 0_u32;
     let fee = 0; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
 
     let payment = Payment::new(
@@ -619,7 +639,7 @@ fn serialize_payment_zero_values() {
         ticket_number,
         fee,
         source_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_payment_json = r"{
@@ -634,7 +654,7 @@ fn serialize_payment_zero_values() {
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_no_signature = payment.binary_serialize(true);
+    let encoded_no_signature = payment.binary_serialize(true).unwrap();
 
     assert_decodes(encoded_no_signature.as_slice(), expected_payment_json);
 }
@@ -669,7 +689,7 @@ fn encode_for_multi_signing() {
           let accountId = xrpl.encodeAccountID(Buffer.from("{}", "hex"));
           console.log(xrpl.encodeForMultiSigning(tx, accountId));
         "#,
-        hex::encode(&payment.binary_serialize(true)),
+        hex::encode(&payment.binary_serialize(true).unwrap()),
         hex::encode(xrpl_codec::utils::secp256k1_public_key_to_account_id(
             signing_pub_key
         )),
@@ -693,8 +713,8 @@ fn encode_for_multi_signing() {
         xrpl_js_output,
         hex::encode(&xrpl_codec::utils::encode_for_multi_signing(
             &payment,
-            signing_pub_key
-        )),
+            PublicKey::try_from(signing_pub_key).unwrap()
+        ).unwrap()),
     );
 }
 
@@ -702,8 +722,8 @@ fn encode_for_multi_signing() {
 fn public_key_to_account_id() {
     let pub_key: [u8; 33] =
         hex_literal::hex!("EDDF4ECB8F34A168143B928D48EFE625501FB8552403BBBD3FC038A5788951D770");
-    let account_id_bytes = hex::encode(xrpl_codec::utils::secp256k1_public_key_to_account_id(
-        pub_key,
+    let account_id_bytes = hex::encode(xrpl_codec::utils::public_key_to_account_id(
+        PublicKey::try_from(pub_key).unwrap(),
     ));
 
     let js_test = format!(
@@ -738,7 +758,7 @@ fn decode_SignerListSet_tx() {
     let fee = 1_000; // 1000 drops
     let nonce = 1_u32;
     let ticket_number = 1_u32;
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let signer_quorum = 3_u32;
     let mut signer_entries = Vec::<([u8; 20], u16)>::default();
     signer_entries.push(([1_u8; 20], 1_u16));
@@ -753,10 +773,10 @@ fn decode_SignerListSet_tx() {
         signer_quorum,
         signer_entries.clone(),
         source_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
-    let encoded_no_signature = signer_list_set.binary_serialize(true);
+    let encoded_no_signature = signer_list_set.binary_serialize(true).unwrap();
 
     let expected_signer_list_set_json = r"{
         TransactionType: 'SignerListSet',
@@ -789,7 +809,7 @@ fn decode_SignerListSet_tx() {
         expected_signer_list_set_json,
     );
     // with signature
-    signer_list_set.attach_signature([7_u8; 65]);
+    signer_list_set.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_signer_list_set_json = r"{
         TransactionType: 'SignerListSet',
         Flags: 2147483648,
@@ -799,7 +819,7 @@ fn decode_SignerListSet_tx() {
         TicketSequence: 1,
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         SignerEntries: [
             {
@@ -816,7 +836,7 @@ fn decode_SignerListSet_tx() {
             }
         ]
     }";
-    let encoded_with_signature = signer_list_set.binary_serialize(false);
+    let encoded_with_signature = signer_list_set.binary_serialize(false).unwrap();
     assert_decodes(
         encoded_with_signature.as_slice(),
         expected_signer_list_set_json,
@@ -830,7 +850,7 @@ fn decode_SignerListSet_tx_empty_signer_entries() {
     let fee = 1_000; // 1000 drops
     let nonce = 1_u32;
     let ticket_number = 1_u32;
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let signer_quorum = 3_u32;
     let source_tag = 38_887_387_u32;
     // let mut signer_entries = Vec::<([u8; 20], u16)>::default();
@@ -845,10 +865,10 @@ fn decode_SignerListSet_tx_empty_signer_entries() {
         signer_quorum,
         Default::default(),
         source_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
-    let encoded_no_signature = signer_list_set.binary_serialize(true);
+    let encoded_no_signature = signer_list_set.binary_serialize(true).unwrap();
 
     let expected_signer_list_set_json = r"{
         TransactionType: 'SignerListSet',
@@ -868,7 +888,7 @@ fn decode_SignerListSet_tx_empty_signer_entries() {
         expected_signer_list_set_json,
     );
     // with signature
-    signer_list_set.attach_signature([7_u8; 65]);
+    signer_list_set.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_signer_list_set_json = r"{
         TransactionType: 'SignerListSet',
         Flags: 2147483648,
@@ -878,11 +898,11 @@ fn decode_SignerListSet_tx_empty_signer_entries() {
         TicketSequence: 1,
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         SignerEntries: []
     }";
-    let encoded_with_signature = signer_list_set.binary_serialize(false);
+    let encoded_with_signature = signer_list_set.binary_serialize(false).unwrap();
     assert_decodes(
         encoded_with_signature.as_slice(),
         expected_signer_list_set_json,
@@ -898,7 +918,7 @@ fn serialize_nftoken_create_offer_tx() {
     let sequence = 0_u32;
     let ticket_number = 1_u32;
     let fee = 1_000; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
     let mut nftoken_create_offer = NFTokenCreateOffer::new(
         account,
@@ -909,7 +929,7 @@ fn serialize_nftoken_create_offer_tx() {
         ticket_number,
         fee,
         source_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_offer_json = r"{
@@ -925,12 +945,12 @@ fn serialize_nftoken_create_offer_tx() {
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_no_signature = nftoken_create_offer.binary_serialize(true);
+    let encoded_no_signature = nftoken_create_offer.binary_serialize(true).unwrap();
 
     assert_decodes(encoded_no_signature.as_slice(), expected_offer_json);
 
     // with signature
-    nftoken_create_offer.attach_signature([7_u8; 65]);
+    nftoken_create_offer.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_offer_json = r"{
         TransactionType: 'NFTokenCreateOffer',
         Flags: 1,
@@ -941,11 +961,11 @@ fn serialize_nftoken_create_offer_tx() {
         Amount: '0',
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC',
         Destination: 'rBcktgVfNjHmxNAQDEE66ztz4qZkdngdm'
     }";
-    let encoded_with_signature = nftoken_create_offer.binary_serialize(false);
+    let encoded_with_signature = nftoken_create_offer.binary_serialize(false).unwrap();
     assert_decodes(encoded_with_signature.as_slice(), expected_offer_json);
 }
 
@@ -956,7 +976,7 @@ fn serialize_nftoken_accept_offer_tx() {
     let sequence = 0_u32;
     let ticket_number = 1_u32;
     let fee = 1_000; // 1000 drops
-    let signing_pub_key = [1_u8; 33];
+    let signing_pub_key = [2_u8; 33];
     let source_tag = 38_887_387_u32;
     let mut nftoken_accept_offer = NFTokenAcceptOffer::new(
         account,
@@ -965,7 +985,7 @@ fn serialize_nftoken_accept_offer_tx() {
         ticket_number,
         fee,
         source_tag,
-        Some(signing_pub_key),
+        Some(PublicKey::try_from(signing_pub_key).unwrap()),
     );
 
     let expected_accept_offer_json = r"{
@@ -978,12 +998,12 @@ fn serialize_nftoken_accept_offer_tx() {
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC'
     }";
-    let encoded_no_signature = nftoken_accept_offer.binary_serialize(true);
+    let encoded_no_signature = nftoken_accept_offer.binary_serialize(true).unwrap();
 
     assert_decodes(encoded_no_signature.as_slice(), expected_accept_offer_json);
 
     // with signature
-    nftoken_accept_offer.attach_signature([7_u8; 65]);
+    nftoken_accept_offer.attach_signature(DerSignature::try_from([7_u8; 65]).unwrap()).unwrap();
     let expected_accept_offer_json = r"{
         TransactionType: 'NFTokenAcceptOffer',
         SourceTag: 38887387,
@@ -992,13 +1012,33 @@ fn serialize_nftoken_accept_offer_tx() {
         NFTokenSellOffer: '0303030303030303030303030303030303030303030303030303030303030303',
         Fee: '1000',
         SigningPubKey: '010101010101010101010101010101010101010101010101010101010101010101',
-        TxnSignature: '0707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707070707',
+        TxnSignature: '30440220070707070707070707070707070707070707070707070707070707070707070702200707070707070707070707070707070707070707070707070707070707070707',
         Account: 'raJ1Aqkhf19P7cyUc33MMVAzgvHPvtNFC'
     }";
-    let encoded_with_signature = nftoken_accept_offer.binary_serialize(false);
+    let encoded_with_signature = nftoken_accept_offer.binary_serialize(false).unwrap();
     // println!("{:?}", hex::encode(encoded_with_signature.clone()));
     assert_decodes(
         encoded_with_signature.as_slice(),
         expected_accept_offer_json,
     );
 }
+
+#[test]
+fn transaction_hash_signed_payment() {
+    let mut payment = Payment::new(
+        [1_u8; 20],
+        [2_u8; 20],
+        5_000_000,
+        1,
+        0,
+        12,
+        1,
+        Some(PublicKey::try_from([3_u8; 33]).unwrap()),
+    );
+    payment.attach_signature(DerSignature::try_from([4_u8; 65]).unwrap()).unwrap();
+
+    assert_hash(
+        payment.binary_serialize(false).unwrap().as_slice(),
+        transaction_hash(&payment).unwrap(),
+    );
+}
@@ -2,54 +2,119 @@
 #![no_std]
 extern crate alloc;
 extern crate proc_macro;
+// used to read alternative definitions files at compile time
+extern crate std;
 
-use alloc::string::ToString;
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
 use hashbrown::HashMap;
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{format_ident, quote};
 use serde_json::Value;
-use syn::{parse_macro_input, Data, DeriveInput, Fields};
+use std::sync::Mutex;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    parse_macro_input, Attribute, Data, DeriveInput, Fields, GenericArgument, Ident, Index, LitStr,
+    Member, PathArguments, Token, Type,
+};
 
 /// XRPL codec definitions file
-/// https://github.com/XRPLF/xrpl.js/blob/8a9a9bcc28ace65cde46eed5010eb8927374a736/packages/ripple-binary-codec/src/enums/definitions.json
+/// https://github.com/XRPLF/xrpl.js/blob/main/packages/ripple-binary-codec/src/enums/definitions.json
 static DEFINITIONS: &str = include_str!("../res/definitions.json");
 
+/// Path of an alternative definitions file e.g. for a private network or sidechain
+///
+/// Used by every macro invocation without an explicit definitions path, including those within the codec itself.
+/// Relative paths are resolved against the manifest dir of the crate being compiled, so prefer an absolute path
+/// (e.g. `[env]` with `relative = true` in '.cargo/config.toml')
+const DEFINITIONS_ENV: &str = "XRPL_CODEC_DEFINITIONS";
+
 lazy_static::lazy_static! {
-    /// XRPL codec definitions parsed
-    static ref DEFINITIONS_JSON: serde_json::Value = serde_json::from_str(DEFINITIONS).expect("JSON was not well-formatted");
+    /// XRPL codec definitions parsed, by (resolved) file path or `None` for the bundled definitions
+    static ref LOADED_DEFINITIONS: Mutex<HashMap<Option<String>, &'static Definitions>> = Mutex::new(HashMap::new());
 }
 
-lazy_static::lazy_static! {
+/// Parsed XRPL codec definitions
+struct Definitions {
+    /// The resolved path of the definitions file or `None` for the bundled definitions
+    path: Option<String>,
+    json: &'static Value,
     /// XRPL codec fields
-    static ref FIELDS: HashMap<&'static str, FieldMetadata> = {
+    fields: HashMap<&'static str, FieldMetadata>,
+    /// XRPL codec field names in 'definitions.json' order
+    field_names: Vec<&'static str>,
+}
+
+impl Definitions {
+    /// Load the definitions at `path` (relative to the manifest dir of the crate being compiled),
+    /// the `XRPL_CODEC_DEFINITIONS` file or the bundled definitions in that order of preference
+    fn load(path: Option<String>) -> &'static Self {
+        let path = path
+            .or_else(|| std::env::var(DEFINITIONS_ENV).ok())
+            .map(|path| {
+                let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+                std::path::Path::new(&manifest_dir)
+                    .join(path)
+                    .to_string_lossy()
+                    .to_string()
+            });
+
+        let mut loaded = LOADED_DEFINITIONS
+            .lock()
+            .expect("definitions lock is not poisoned");
+        loaded.entry(path.clone()).or_insert_with(|| {
+            let definitions: &'static str = match &path {
+                Some(path) => Box::leak(
+                    std::fs::read_to_string(path)
+                        .unwrap_or_else(|err| {
+                            panic!("failed to read definitions {}: {}", path, err)
+                        })
+                        .into_boxed_str(),
+                ),
+                None => DEFINITIONS,
+            };
+            Box::leak(Box::new(Self::parse(path, definitions)))
+        })
+    }
+
+    fn parse(path: Option<String>, definitions: &'static str) -> Self {
+        let json: &'static Value = Box::leak(Box::new(
+            serde_json::from_str(definitions).expect("JSON was not well-formatted"),
+        ));
         let mut f = HashMap::new();
 
-        let fields = if let Value::Array(fields) = &DEFINITIONS_JSON["FIELDS"] {
+        let fields = if let Value::Array(fields) = &json["FIELDS"] {
             fields
         } else {
             panic!("invalid fields in definitions.json");
         };
 
         for field in fields {
-            if let [
-                Value::String(field_name),
-                Value::Object(field_metadata)
-            ] = field.as_array().expect("field is a kv tuple").as_slice() {
-                let field_type_key =
-                if let Value::String(field_type) = &field_metadata["type"] {
+            if let [Value::String(field_name), Value::Object(field_metadata)] =
+                field.as_array().expect("field is a kv tuple").as_slice()
+            {
+                let field_type_key = if let Value::String(field_type) = &field_metadata["type"] {
                     field_type
                 } else {
                     panic!("invalid field type in definitions.json");
                 };
 
-                let field_code: u16 = if let Value::Number(n) = &field_metadata["nth"] {
-                    n.as_u64().unwrap_or(0) as u16
+                let (field_code, nth): (u16, i16) = if let Value::Number(n) = &field_metadata["nth"]
+                {
+                    (
+                        n.as_u64().unwrap_or(0) as u16,
+                        n.as_i64().expect("nth is an integer") as i16,
+                    )
                 } else {
                     panic!("invalid field code in definitions.json");
                 };
 
-                let type_code: u16 = if let Value::Number(n) = &DEFINITIONS_JSON["TYPES"][field_type_key] {
+                let type_code: u16 = if let Value::Number(n) = &json["TYPES"][field_type_key] {
                     n.as_u64().unwrap_or(0) as u16
                 } else {
                     panic!("invalid type code in definitions.json");
@@ -65,28 +130,71 @@ lazy_static::lazy_static! {
                 } else {
                     panic!("invalid bool value in definitions.json");
                 };
-                let is_signing_field =
-                    if let Value::Bool(b) = field_metadata["isSigningField"] {
-                        b
-                    } else {
-                        panic!("invalid bool value in definitions.json");
-                    };
+                let is_signing_field = if let Value::Bool(b) = field_metadata["isSigningField"] {
+                    b
+                } else {
+                    panic!("invalid bool value in definitions.json");
+                };
 
                 let m = FieldMetadata {
                     type_code,
                     field_code,
+                    nth,
+                    type_name: field_type_key.as_str(),
                     is_vl_encoded,
                     is_serialized,
                     is_signing_field,
                 };
 
-               f.insert(field_name.as_str(), m);
+                f.insert(field_name.as_str(), m);
             } else {
                 panic!("invalid field in definitions.json");
             }
         }
-        f
-    };
+
+        let field_names = fields
+            .iter()
+            .map(|field| field[0].as_str().expect("field name is a string"))
+            .collect();
+
+        Self {
+            path,
+            json,
+            fields: f,
+            field_names,
+        }
+    }
+
+    /// Lookup the field `name`, failing compilation if it is not defined
+    fn field(&self, name: &str) -> &FieldMetadata {
+        self.fields.get(name).unwrap_or_else(|| {
+            panic!(
+                "field {} is not in {}",
+                name,
+                self.path.as_deref().unwrap_or("definitions.json")
+            )
+        })
+    }
+
+    /// Makes the expanding crate recompile when an alternative definitions file changes
+    fn track(&self) -> TokenStream2 {
+        match &self.path {
+            Some(path) => quote! { const _: &[u8] = include_bytes!(#path); },
+            None => quote! {},
+        }
+    }
+}
+
+/// The definitions path given by a `#[definitions("path/to/definitions.json")]` attribute, if any
+fn definitions_attr(attrs: &[Attribute]) -> Option<String> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("definitions"))
+        .map(|attr| {
+            attr.parse_args::<LitStr>()
+                .expect("definitions is a path string")
+                .value()
+        })
 }
 
 #[derive(Debug)]
@@ -94,22 +202,285 @@ lazy_static::lazy_static! {
 struct FieldMetadata {
     pub field_code: u16,
     pub type_code: u16,
+    /// signed field code as given by 'definitions.json' (e.g. -1 for "Invalid")
+    pub nth: i16,
+    pub type_name: &'static str,
     pub is_serialized: bool,
     pub is_vl_encoded: bool,
     pub is_signing_field: bool,
 }
 
-#[proc_macro_derive(Field)]
+/// An optional definitions path given as a string literal e.g. `field_definitions!("res/definitions.json")`
+struct DefinitionsPath(Option<String>);
+
+impl Parse for DefinitionsPath {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self(None));
+        }
+        Ok(Self(Some(input.parse::<LitStr>()?.value())))
+    }
+}
+
+/// Expands to an array of `FieldDefinition`s for every field in 'definitions.json'
+///
+/// Takes an optional path to alternative definitions e.g. `field_definitions!("res/definitions.json")`
+#[proc_macro]
+pub fn field_definitions(input: TokenStream) -> TokenStream {
+    let DefinitionsPath(path) = parse_macro_input!(input as DefinitionsPath);
+    let defs = Definitions::load(path);
+
+    let mut definitions = TokenStream2::new();
+    for name in defs.field_names.iter() {
+        let FieldMetadata {
+            nth,
+            type_name,
+            is_vl_encoded,
+            is_serialized,
+            is_signing_field,
+            ..
+        } = *defs.field(name);
+        let type_code = defs.json["TYPES"][type_name]
+            .as_i64()
+            .expect("type code is an integer") as i16;
+
+        definitions.extend::<TokenStream2>(quote! {
+            FieldDefinition {
+                name: #name,
+                nth: #nth,
+                type_name: #type_name,
+                type_code: #type_code,
+                is_vl_encoded: #is_vl_encoded,
+                is_serialized: #is_serialized,
+                is_signing_field: #is_signing_field,
+            },
+        });
+    }
+
+    let track = defs.track();
+    quote! { { #track [#definitions] } }.into()
+}
+
+/// Field structs whose inner type is given explicitly rather than by the field's XRPL type
+/// e.g. `SignerEntry(SignerEntryType); SignerEntries(STArrayType<SignerEntry>);`
+/// optionally preceded by a definitions path e.g. `"res/definitions.json";`
+struct FieldOverrides(Option<String>, Vec<(Ident, Type)>);
+
+impl Parse for FieldOverrides {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut path = None;
+        if input.peek(LitStr) {
+            path = Some(input.parse::<LitStr>()?.value());
+            input.parse::<Token![;]>()?;
+        }
+        let mut overrides = Vec::new();
+        while !input.is_empty() {
+            let name = input.parse::<Ident>()?;
+            let content;
+            parenthesized!(content in input);
+            overrides.push((name, content.parse::<Type>()?));
+            input.parse::<Token![;]>()?;
+        }
+        Ok(Self(path, overrides))
+    }
+}
+
+/// The codec type which holds values of the XRPL type `type_name`, if supported
+fn codec_type(type_name: &str) -> Option<TokenStream2> {
+    let codec_type = match type_name {
+        "UInt8" => quote! { UInt8Type },
+        "UInt16" => quote! { UInt16Type },
+        "UInt32" => quote! { UInt32Type },
+        "UInt64" => quote! { UInt64Type },
+        "UInt96" => quote! { UInt96Type },
+        "UInt192" => quote! { UInt192Type },
+        "UInt384" => quote! { UInt384Type },
+        "UInt512" => quote! { UInt512Type },
+        "Hash128" => quote! { Hash128Type },
+        "Hash160" => quote! { Hash160Type },
+        "Hash256" => quote! { Hash256Type },
+        "Amount" => quote! { AmountType },
+        "Blob" => quote! { BlobType },
+        "AccountID" => quote! { AccountIdType },
+        "Number" => quote! { NumberType },
+        "PathSet" => quote! { PathSetType },
+        "Vector256" => quote! { Vector256Type },
+        "Issue" => quote! { IssueType },
+        "XChainBridge" => quote! { XChainBridgeType },
+        "Currency" => quote! { CurrencyType },
+        "STObject" => quote! { STObjectType },
+        "STArray" => quote! { STArrayType<STArrayItemType> },
+        _ => return None,
+    };
+    Some(codec_type)
+}
+
+/// Generates a `Field` struct for every serialized field in 'definitions.json' which has a supported type
+///
+/// The input lists fields with an explicit inner type e.g. `SignerEntry(SignerEntryType);`
+/// optionally preceded by a path to alternative definitions e.g. `"res/definitions.json";`
+#[proc_macro]
+pub fn codec_fields(input: TokenStream) -> TokenStream {
+    let FieldOverrides(path, overrides) = parse_macro_input!(input as FieldOverrides);
+    let defs = Definitions::load(path);
+
+    let mut structs = TokenStream2::new();
+    structs.extend(defs.track());
+    for name in defs.field_names.iter() {
+        let FieldMetadata {
+            field_code,
+            type_name,
+            is_serialized,
+            ..
+        } = *defs.field(name);
+        // the end markers are not fields
+        if !is_serialized
+            || (field_code == 1 && (type_name == "STObject" || type_name == "STArray"))
+        {
+            continue;
+        }
+
+        let ident = format_ident!("{}", name);
+        let inner = if let Some((_, ty)) = overrides.iter().find(|(o, _)| *o == ident) {
+            quote! { #ty }
+        } else if let Some(codec_type) = codec_type(type_name) {
+            codec_type
+        } else {
+            continue;
+        };
+        // variable length and array values have a natural empty default
+        let default = if matches!(type_name, "Blob" | "Vector256" | "STArray") {
+            quote! { Default, }
+        } else {
+            quote! {}
+        };
+        // variable length, object and array values have no max. SCALE encoded length
+        let max_encoded_len = if overrides.iter().any(|(o, _)| *o == ident)
+            || matches!(
+                type_name,
+                "Blob" | "Vector256" | "PathSet" | "STObject" | "STArray"
+            ) {
+            quote! {}
+        } else {
+            quote! { , codec::MaxEncodedLen }
+        };
+        // the generated structs carry the definitions path for their `Field` derive
+        let definitions_attr = defs
+            .path
+            .as_ref()
+            .map(|path| quote! { #[definitions(#path)] });
+        let doc = alloc::format!(" The '{}' field ({}, nth {})", name, type_name, field_code);
+
+        structs.extend::<TokenStream2>(quote! {
+            #[doc = #doc]
+            #[derive(Field, Debug, Clone, #default PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+            #[cfg_attr(feature = "scale", derive(codec::Encode, codec::Decode, scale_info::TypeInfo #max_encoded_len))]
+            #definitions_attr
+            pub struct #ident(pub #inner);
+        });
+    }
+
+    structs.into()
+}
+
+/// Generates the `TransactionTypeCode` enum from 'definitions.json'
+///
+/// Takes an optional path to alternative definitions e.g. `transaction_type_codes!("res/definitions.json")`
+#[proc_macro]
+pub fn transaction_type_codes(input: TokenStream) -> TokenStream {
+    let DefinitionsPath(path) = parse_macro_input!(input as DefinitionsPath);
+    let defs = Definitions::load(path);
+    let transaction_types =
+        if let Value::Object(transaction_types) = &defs.json["TRANSACTION_TYPES"] {
+            transaction_types
+        } else {
+            panic!("invalid transaction types in definitions.json");
+        };
+
+    // ordered by code, skipping "Invalid" = -1
+    let mut transaction_types: Vec<(&str, u16)> = transaction_types
+        .iter()
+        .filter_map(|(name, code)| match code.as_i64() {
+            Some(code @ 0..) => Some((name.as_str(), code as u16)),
+            _ => None,
+        })
+        .collect();
+    transaction_types.sort_by_key(|(_, code)| *code);
+
+    let mut variants = TokenStream2::new();
+    let mut names = TokenStream2::new();
+    let mut from_codes = TokenStream2::new();
+    let mut from_names = TokenStream2::new();
+    for (name, code) in transaction_types {
+        let ident = format_ident!("{}", name);
+        variants.extend::<TokenStream2>(quote! { #ident = #code, });
+        names.extend::<TokenStream2>(quote! { Self::#ident => #name, });
+        from_codes.extend::<TokenStream2>(quote! { #code => Some(Self::#ident), });
+        from_names.extend::<TokenStream2>(quote! { #name => Some(Self::#ident), });
+    }
+
+    let track = defs.track();
+    quote! {
+        #track
+
+        /// XRPL TransactionTypes
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(u16)]
+        pub enum TransactionTypeCode {
+            #variants
+        }
+
+        impl TransactionTypeCode {
+            pub fn code(self) -> u16 {
+                self as u16
+            }
+
+            /// The name of the transaction type e.g. "Payment"
+            pub fn name(self) -> &'static str {
+                match self {
+                    #names
+                }
+            }
+
+            /// The transaction type with the given `code`, if any
+            pub fn from_code(code: u16) -> Option<Self> {
+                match code {
+                    #from_codes
+                    _ => None,
+                }
+            }
+
+            /// The transaction type with the given `name` e.g. "Payment", if any
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    #from_names
+                    _ => None,
+                }
+            }
+        }
+    }
+    .into()
+}
+
+/// Derives `CodecField` and `BinaryDeserialize` for a field struct named after its 'definitions.json' entry
+///
+/// Alternative definitions may be given with `#[definitions("path/to/definitions.json")]`
+#[proc_macro_derive(Field, attributes(definitions))]
 pub fn derive_macro_field(input: TokenStream) -> TokenStream {
     self::derive_proc_macro_impl(input)
 }
 
 fn derive_proc_macro_impl(input: TokenStream) -> TokenStream {
     let DeriveInput {
-        ident, generics, ..
+        ident,
+        generics,
+        attrs,
+        ..
     } = parse_macro_input!(input as DeriveInput);
     let where_clause = &generics.where_clause;
     let field_key = ident.to_string();
+    let defs = Definitions::load(definitions_attr(&attrs));
 
     let FieldMetadata {
         type_code,
@@ -117,9 +488,13 @@ fn derive_proc_macro_impl(input: TokenStream) -> TokenStream {
         is_vl_encoded,
         is_serialized,
         is_signing_field,
-    } = FIELDS[field_key.as_str()];
+        ..
+    } = *defs.field(field_key.as_str());
+    let track = defs.track();
 
     quote! {
+        #track
+
         impl #generics CodecField for #ident #generics #where_clause {
           fn field_code(&self) -> u16 {
             #field_code
@@ -140,62 +515,244 @@ fn derive_proc_macro_impl(input: TokenStream) -> TokenStream {
             &self.0 as &dyn BinarySerialize
           }
       }
+
+      impl #generics BinaryDeserialize for #ident #generics #where_clause {
+          fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+            decode_field_value(buf, #type_code, #field_code, #is_vl_encoded).map(Self)
+          }
+      }
     }
     .into()
 }
 
-#[proc_macro_derive(Transaction)]
+/// The `T` of an `Option<T>` type
+fn optional_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Derives the codec traits for a transaction struct of `Field`s
+///
+/// `Option<Field>` members are optional fields
+///
+/// The `TransactionType` is given by `#[transaction_type(Payment)]` and alternative definitions may be given with
+/// `#[definitions("path/to/definitions.json")]`
+///
+/// A checked SCALE `Decode` is derived too given `#[scale_decode]` (e.g. `#[cfg_attr(feature = "scale", scale_decode)]`),
+/// so all fields must implement `codec::Decode`
+#[proc_macro_derive(Transaction, attributes(transaction_type, definitions, scale_decode))]
 pub fn derive_macro_transaction(input: TokenStream) -> TokenStream {
     self::derive_proc_macro_impl_transaction(input)
 }
 
+/// Fields of a tx that are absent when it is encoded for signing
+const SIGNATURE_FIELDS: &[&str] = &["TxnSignature", "Signers"];
+
 fn derive_proc_macro_impl_transaction(input: TokenStream) -> TokenStream {
     let DeriveInput {
         ident,
         generics,
         data,
+        attrs,
         ..
     } = parse_macro_input!(input as DeriveInput);
     let where_clause = &generics.where_clause;
 
-    let mut fields = TokenStream2::new();
+    // the expected `TransactionTypeCode` e.g. `#[transaction_type(Payment)]`
+    let transaction_type_code = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("transaction_type"))
+        .map(|attr| {
+            attr.parse_args::<Ident>()
+                .expect("transaction_type is a TransactionTypeCode variant")
+        })
+        .expect("missing #[transaction_type(..)] attribute");
+    let defs = Definitions::load(definitions_attr(&attrs));
+
+    let mut members = Vec::<(Member, Type)>::new();
     if let Data::Struct(struct_data) = data {
         // normal struct Struct{ a, b, c }
         if let Fields::Named(fields_named) = struct_data.fields {
             for field in fields_named.named {
                 let field_name = field.ident.expect("field has an ident");
-                fields.extend::<TokenStream2>(quote! { &self.#field_name as &dyn CodecField, });
+                members.push((Member::Named(field_name), field.ty));
             }
         // tuple struct Struct(a,b,c)
         } else if let Fields::Unnamed(unnamed_fields) = struct_data.fields {
-            for idx in 0..unnamed_fields.unnamed.len() {
-                fields.extend::<TokenStream2>(quote! { &self.#idx as &dyn CodecField, });
+            for (idx, field) in unnamed_fields.unnamed.into_iter().enumerate() {
+                members.push((Member::Unnamed(Index::from(idx)), field.ty));
             }
         }
     }
 
+    let mut fields = TokenStream2::new();
+    let mut decode_locals = TokenStream2::new();
+    let mut decode_arms = TokenStream2::new();
+    let mut decode_members = TokenStream2::new();
+    let mut scale_decode_members = TokenStream2::new();
+    let mut transaction_type_member = None;
+    for (idx, (member, ty)) in members.iter().enumerate() {
+        // `Option<Field>` members are optional fields i.e. only (de)serialized when present
+        let (ty, is_optional) = match optional_inner_type(ty) {
+            Some(inner) => (inner, true),
+            None => (ty, false),
+        };
+        if is_optional {
+            fields.extend::<TokenStream2>(quote! {
+                if let Some(field) = &self.#member {
+                    fields_.push(field as &dyn CodecField);
+                }
+            });
+        } else {
+            fields
+                .extend::<TokenStream2>(quote! { fields_.push(&self.#member as &dyn CodecField); });
+        }
+
+        let field_key = if let Type::Path(type_path) = ty {
+            type_path
+                .path
+                .segments
+                .last()
+                .expect("field type has a name")
+                .ident
+                .to_string()
+        } else {
+            panic!("transaction fields must be codec field types");
+        };
+        let FieldMetadata {
+            type_code,
+            field_code,
+            is_signing_field,
+            ..
+        } = *defs.field(field_key.as_str());
+        if field_key == "TransactionType" {
+            transaction_type_member = Some(member.clone());
+        }
+
+        scale_decode_members
+            .extend::<TokenStream2>(quote! { #member: codec::Decode::decode(input)?, });
+
+        let local = format_ident!("field_{}", idx);
+        decode_locals.extend::<TokenStream2>(quote! { let mut #local: Option<#ty> = None; });
+        decode_arms.extend::<TokenStream2>(quote! {
+            (#type_code, #field_code) => #local = Some(<#ty>::binary_deserialize_from(buf)?),
+        });
+        // the signature fields are stripped from a tx encoded for signing, any other required field is not
+        if is_optional {
+            decode_members.extend::<TokenStream2>(quote! { #member: #local, });
+        } else if !is_signing_field && SIGNATURE_FIELDS.contains(&field_key.as_str()) {
+            decode_members.extend::<TokenStream2>(quote! { #member: #local.unwrap_or_default(), });
+        } else {
+            decode_members.extend::<TokenStream2>(quote! {
+                #member: #local.ok_or_else(|| {
+                    Error::InvalidData(alloc::format!("missing field {}", #field_key))
+                })?,
+            });
+        }
+    }
+    let transaction_type_member =
+        transaction_type_member.expect("transaction has a TransactionType field");
+    let track = defs.track();
+
+    // the field values check their own invariants when SCALE decoded, the tx checks its `TransactionType`
+    let scale_decode = if attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("scale_decode"))
+    {
+        quote! {
+            /// Fails unless the `TransactionType` matches (and the fields are valid)
+            impl codec::Decode for #ident {
+                fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+                    let decoded = Self { #scale_decode_members };
+                    if decoded.#transaction_type_member.0 .0 != TransactionTypeCode::#transaction_type_code.code() {
+                        return Err("unexpected TransactionType".into());
+                    }
+                    Ok(decoded)
+                }
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+
     quote! {
+        #track
+
         impl #generics CodecToFields for #ident #generics #where_clause {
             fn to_canonical_fields(&self) -> Vec<&dyn CodecField> {
-                let mut fields_ = [#fields];
+                let mut fields_ = Vec::<&dyn CodecField>::new();
+                #fields
                 // Sort in canonical order
-                fields_.sort_by(|a, b| {
-                    let field_order = a.type_code().cmp(&b.type_code());
-                    if let core::cmp::Ordering::Equal = field_order {
-                        a.field_code().cmp(&b.field_code())
-                    } else {
-                        field_order
-                    }
-                });
-                fields_.to_vec()
+                fields_.sort_by_key(|f| f.field_id());
+                fields_
             }
         }
 
         impl BinarySerialize for #ident {
-            fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) {
+            fn binary_serialize_to(&self, buf: &mut Vec<u8>, for_signing: bool) -> Result<(), Error> {
                 for f in self.to_canonical_fields().iter_mut() {
-                    f.binary_serialize_to(buf, for_signing);
+                    f.binary_serialize_to(buf, for_signing)?;
                 }
+                Ok(())
+            }
+        }
+
+        impl BinaryDeserialize for #ident {
+            fn binary_deserialize_from(buf: &mut &[u8]) -> Result<Self, Error> {
+                #decode_locals
+                let mut last_field_id: Option<FieldId> = None;
+                while !buf.is_empty() {
+                    // peek the field header, the field decoder consumes it
+                    let field_id = FieldId::decode(&mut &buf[..])?;
+                    if let Some(last_field_id) = last_field_id {
+                        if field_id == last_field_id {
+                            return Err(Error::InvalidData(alloc::format!("duplicate field {:?}", field_id)));
+                        }
+                        if field_id < last_field_id {
+                            return Err(Error::InvalidData(alloc::format!("field {:?} is not in canonical order", field_id)));
+                        }
+                    }
+                    last_field_id = Some(field_id);
+
+                    match (field_id.type_code, field_id.field_code) {
+                        #decode_arms
+                        _ => return Err(Error::InvalidData(alloc::format!("unknown field {:?}", field_id))),
+                    }
+                }
+
+                let decoded = Self { #decode_members };
+                if decoded.#transaction_type_member.0 .0 != TransactionTypeCode::#transaction_type_code.code() {
+                    return Err(Error::InvalidData(alloc::format!(
+                        "expected TransactionType {}, found {}",
+                        TransactionTypeCode::#transaction_type_code.code(),
+                        decoded.#transaction_type_member.0 .0,
+                    )));
+                }
+
+                Ok(decoded)
+            }
+        }
+
+        #scale_decode
+
+        impl #ident {
+            /// Decode a binary serialized tx (in either signing or non-signing mode)
+            ///
+            /// Fails if the `TransactionType` does not match, or on any unknown, duplicate or out of order field
+            pub fn decode(data: &[u8]) -> Result<Self, Error> {
+                Self::binary_deserialize(data)
             }
         }
     }